@@ -1,7 +1,14 @@
 pub mod chapters;
+pub mod dictionary_entries;
+pub mod import_jobs;
 pub mod prelude;
+pub mod quiz_questions;
+pub mod quiz_sessions;
+pub mod review_logs;
+pub mod review_states;
 pub mod tags;
 pub mod users;
+pub mod word_examples;
 pub mod word_tags;
 pub mod wordbooks;
 pub mod words;
@@ -20,12 +20,44 @@ pub struct Model {
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::import_jobs::Entity")]
+    ImportJobs,
+    #[sea_orm(has_many = "super::quiz_sessions::Entity")]
+    QuizSessions,
+    #[sea_orm(has_many = "super::review_logs::Entity")]
+    ReviewLogs,
+    #[sea_orm(has_many = "super::review_states::Entity")]
+    ReviewStates,
     #[sea_orm(has_many = "super::tags::Entity")]
     Tags,
     #[sea_orm(has_many = "super::wordbooks::Entity")]
     Wordbooks,
 }
 
+impl Related<super::import_jobs::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ImportJobs.def()
+    }
+}
+
+impl Related<super::quiz_sessions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::QuizSessions.def()
+    }
+}
+
+impl Related<super::review_logs::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ReviewLogs.def()
+    }
+}
+
+impl Related<super::review_states::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ReviewStates.def()
+    }
+}
+
 impl Related<super::tags::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Tags.def()
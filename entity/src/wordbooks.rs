@@ -22,6 +22,8 @@ pub struct Model {
 pub enum Relation {
     #[sea_orm(has_many = "super::chapters::Entity")]
     Chapters,
+    #[sea_orm(has_many = "super::import_jobs::Entity")]
+    ImportJobs,
     #[sea_orm(
         belongs_to = "super::users::Entity",
         from = "Column::UserId",
@@ -38,6 +40,12 @@ impl Related<super::chapters::Entity> for Entity {
     }
 }
 
+impl Related<super::import_jobs::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ImportJobs.def()
+    }
+}
+
 impl Related<super::users::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Users.def()
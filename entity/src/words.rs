@@ -16,6 +16,8 @@ pub struct Model {
     pub sort_order: i32,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
+    pub phonetic: Option<String>,
+    pub part_of_speech: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -28,6 +30,14 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Chapters,
+    #[sea_orm(has_many = "super::quiz_questions::Entity")]
+    QuizQuestions,
+    #[sea_orm(has_many = "super::review_logs::Entity")]
+    ReviewLogs,
+    #[sea_orm(has_many = "super::review_states::Entity")]
+    ReviewStates,
+    #[sea_orm(has_many = "super::word_examples::Entity")]
+    WordExamples,
     #[sea_orm(has_many = "super::word_tags::Entity")]
     WordTags,
 }
@@ -38,6 +48,30 @@ impl Related<super::chapters::Entity> for Entity {
     }
 }
 
+impl Related<super::quiz_questions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::QuizQuestions.def()
+    }
+}
+
+impl Related<super::review_logs::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ReviewLogs.def()
+    }
+}
+
+impl Related<super::review_states::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ReviewStates.def()
+    }
+}
+
+impl Related<super::word_examples::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WordExamples.def()
+    }
+}
+
 impl Related<super::word_tags::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::WordTags.def()
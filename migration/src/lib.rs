@@ -1,4 +1,11 @@
 pub mod m20260121_000001_create_tables;
+pub mod m20261017_000001_create_review_states;
+pub mod m20261017_000002_create_quiz_tables;
+pub mod m20261017_000003_create_review_logs;
+pub mod m20261017_000004_create_words_fts;
+pub mod m20261017_000005_add_word_details;
+pub mod m20261017_000006_create_dictionary_entries;
+pub mod m20261017_000007_create_import_jobs;
 
 use sea_orm_migration::prelude::*;
 
@@ -7,6 +14,15 @@ pub struct Migrator;
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20260121_000001_create_tables::Migration)]
+        vec![
+            Box::new(m20260121_000001_create_tables::Migration),
+            Box::new(m20261017_000001_create_review_states::Migration),
+            Box::new(m20261017_000002_create_quiz_tables::Migration),
+            Box::new(m20261017_000003_create_review_logs::Migration),
+            Box::new(m20261017_000004_create_words_fts::Migration),
+            Box::new(m20261017_000005_add_word_details::Migration),
+            Box::new(m20261017_000006_create_dictionary_entries::Migration),
+            Box::new(m20261017_000007_create_import_jobs::Migration),
+        ]
     }
 }
\ No newline at end of file
@@ -0,0 +1,89 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20260121_000001_create_tables::Users;
+use crate::m20260121_000001_create_tables::Words;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ReviewStates::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(ReviewStates::Id).integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(ReviewStates::UserId).integer().not_null())
+                    .col(ColumnDef::new(ReviewStates::WordId).integer().not_null())
+                    .col(ColumnDef::new(ReviewStates::EaseFactor).double().not_null().default(2.5))
+                    .col(ColumnDef::new(ReviewStates::IntervalDays).integer().not_null().default(0))
+                    .col(ColumnDef::new(ReviewStates::Repetitions).integer().not_null().default(0))
+                    .col(ColumnDef::new(ReviewStates::Lapses).integer().not_null().default(0))
+                    .col(ColumnDef::new(ReviewStates::DueAt).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(ReviewStates::LastReviewedAt).timestamp_with_time_zone().null())
+                    .col(ColumnDef::new(ReviewStates::CreatedAt).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(ReviewStates::UpdatedAt).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_review_states_user")
+                            .from(ReviewStates::Table, ReviewStates::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_review_states_word")
+                            .from(ReviewStates::Table, ReviewStates::WordId)
+                            .to(Words::Table, Words::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_review_states_user_word")
+                    .table(ReviewStates::Table)
+                    .col(ReviewStates::UserId)
+                    .col(ReviewStates::WordId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_review_states_user_due")
+                    .table(ReviewStates::Table)
+                    .col(ReviewStates::UserId)
+                    .col(ReviewStates::DueAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(ReviewStates::Table).to_owned()).await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum ReviewStates {
+    Table,
+    Id,
+    UserId,
+    WordId,
+    EaseFactor,
+    IntervalDays,
+    Repetitions,
+    Lapses,
+    DueAt,
+    LastReviewedAt,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,126 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20260121_000001_create_tables::Users;
+use crate::m20260121_000001_create_tables::Words;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(QuizSessions::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(QuizSessions::Id).integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(QuizSessions::UserId).integer().not_null())
+                    .col(ColumnDef::new(QuizSessions::Mode).string_len(20).not_null())
+                    .col(ColumnDef::new(QuizSessions::WordbookId).integer().null())
+                    .col(ColumnDef::new(QuizSessions::ChapterId).integer().null())
+                    .col(ColumnDef::new(QuizSessions::TagIds).string_len(500).null())
+                    .col(ColumnDef::new(QuizSessions::TotalQuestions).integer().not_null())
+                    .col(ColumnDef::new(QuizSessions::CorrectCount).integer().not_null().default(0))
+                    .col(ColumnDef::new(QuizSessions::CompletedAt).timestamp_with_time_zone().null())
+                    .col(ColumnDef::new(QuizSessions::CreatedAt).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_quiz_sessions_user")
+                            .from(QuizSessions::Table, QuizSessions::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_quiz_sessions_user")
+                    .table(QuizSessions::Table)
+                    .col(QuizSessions::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(QuizQuestions::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(QuizQuestions::Id).integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(QuizQuestions::SessionId).integer().not_null())
+                    .col(ColumnDef::new(QuizQuestions::WordId).integer().null())
+                    .col(ColumnDef::new(QuizQuestions::Position).integer().not_null())
+                    .col(ColumnDef::new(QuizQuestions::Prompt).string_len(500).not_null())
+                    .col(ColumnDef::new(QuizQuestions::Options).text().null())
+                    .col(ColumnDef::new(QuizQuestions::ExpectedAnswer).string_len(500).not_null())
+                    .col(ColumnDef::new(QuizQuestions::UserAnswer).text().null())
+                    .col(ColumnDef::new(QuizQuestions::IsCorrect).boolean().null())
+                    .col(ColumnDef::new(QuizQuestions::AnsweredAt).timestamp_with_time_zone().null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_quiz_questions_session")
+                            .from(QuizQuestions::Table, QuizQuestions::SessionId)
+                            .to(QuizSessions::Table, QuizSessions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_quiz_questions_word")
+                            .from(QuizQuestions::Table, QuizQuestions::WordId)
+                            .to(Words::Table, Words::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_quiz_questions_session")
+                    .table(QuizQuestions::Table)
+                    .col(QuizQuestions::SessionId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(QuizQuestions::Table).to_owned()).await?;
+        manager.drop_table(Table::drop().table(QuizSessions::Table).to_owned()).await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum QuizSessions {
+    Table,
+    Id,
+    UserId,
+    Mode,
+    WordbookId,
+    ChapterId,
+    TagIds,
+    TotalQuestions,
+    CorrectCount,
+    CompletedAt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum QuizQuestions {
+    Table,
+    Id,
+    SessionId,
+    WordId,
+    Position,
+    Prompt,
+    Options,
+    ExpectedAnswer,
+    UserAnswer,
+    IsCorrect,
+    AnsweredAt,
+}
\ No newline at end of file
@@ -0,0 +1,79 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20260121_000001_create_tables::Users;
+use crate::m20260121_000001_create_tables::Words;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ReviewLogs::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(ReviewLogs::Id).integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(ReviewLogs::UserId).integer().not_null())
+                    .col(ColumnDef::new(ReviewLogs::WordId).integer().null())
+                    .col(ColumnDef::new(ReviewLogs::Activity).string_len(20).not_null())
+                    .col(ColumnDef::new(ReviewLogs::Grade).integer().null())
+                    .col(ColumnDef::new(ReviewLogs::IsCorrect).boolean().not_null())
+                    .col(ColumnDef::new(ReviewLogs::ReviewedAt).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_review_logs_user")
+                            .from(ReviewLogs::Table, ReviewLogs::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_review_logs_word")
+                            .from(ReviewLogs::Table, ReviewLogs::WordId)
+                            .to(Words::Table, Words::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_review_logs_user_reviewed")
+                    .table(ReviewLogs::Table)
+                    .col(ReviewLogs::UserId)
+                    .col(ReviewLogs::ReviewedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_review_logs_word")
+                    .table(ReviewLogs::Table)
+                    .col(ReviewLogs::WordId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(ReviewLogs::Table).to_owned()).await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum ReviewLogs {
+    Table,
+    Id,
+    UserId,
+    WordId,
+    Activity,
+    Grade,
+    IsCorrect,
+    ReviewedAt,
+}
\ No newline at end of file
@@ -0,0 +1,63 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DbBackend;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const CREATE_WORDS_FTS: &str = "CREATE VIRTUAL TABLE IF NOT EXISTS words_fts USING fts5(
+    source,
+    translation,
+    note,
+    content = 'words',
+    content_rowid = 'id',
+    tokenize = 'trigram'
+)";
+
+const CREATE_INSERT_TRIGGER: &str = "CREATE TRIGGER IF NOT EXISTS words_fts_after_insert AFTER INSERT ON words BEGIN
+    INSERT INTO words_fts(rowid, source, translation, note)
+    VALUES (new.id, new.source, new.translation, new.note);
+END";
+
+const CREATE_DELETE_TRIGGER: &str = "CREATE TRIGGER IF NOT EXISTS words_fts_after_delete AFTER DELETE ON words BEGIN
+    INSERT INTO words_fts(words_fts, rowid, source, translation, note)
+    VALUES ('delete', old.id, old.source, old.translation, old.note);
+END";
+
+const CREATE_UPDATE_TRIGGER: &str = "CREATE TRIGGER IF NOT EXISTS words_fts_after_update AFTER UPDATE OF source, translation, note ON words BEGIN
+    INSERT INTO words_fts(words_fts, rowid, source, translation, note)
+    VALUES ('delete', old.id, old.source, old.translation, old.note);
+    INSERT INTO words_fts(rowid, source, translation, note)
+    VALUES (new.id, new.source, new.translation, new.note);
+END";
+
+const REBUILD_WORDS_FTS: &str = "INSERT INTO words_fts(words_fts) VALUES ('rebuild')";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DbBackend::Sqlite {
+            return Ok(());
+        }
+
+        let db = manager.get_connection();
+        db.execute_unprepared(CREATE_WORDS_FTS).await?;
+        db.execute_unprepared(CREATE_INSERT_TRIGGER).await?;
+        db.execute_unprepared(CREATE_DELETE_TRIGGER).await?;
+        db.execute_unprepared(CREATE_UPDATE_TRIGGER).await?;
+        db.execute_unprepared(REBUILD_WORDS_FTS).await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DbBackend::Sqlite {
+            return Ok(());
+        }
+
+        let db = manager.get_connection();
+        db.execute_unprepared("DROP TRIGGER IF EXISTS words_fts_after_update").await?;
+        db.execute_unprepared("DROP TRIGGER IF EXISTS words_fts_after_delete").await?;
+        db.execute_unprepared("DROP TRIGGER IF EXISTS words_fts_after_insert").await?;
+        db.execute_unprepared("DROP TABLE IF EXISTS words_fts").await?;
+        Ok(())
+    }
+}
\ No newline at end of file
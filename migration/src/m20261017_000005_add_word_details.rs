@@ -0,0 +1,100 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20260121_000001_create_tables::Words;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Words::Table)
+                    .add_column(ColumnDef::new(WordDetails::Phonetic).string_len(200).null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Words::Table)
+                    .add_column(ColumnDef::new(WordDetails::PartOfSpeech).string_len(50).null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(WordExamples::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(WordExamples::Id).integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(WordExamples::WordId).integer().not_null())
+                    .col(ColumnDef::new(WordExamples::Sentence).text().not_null())
+                    .col(ColumnDef::new(WordExamples::Translation).text().null())
+                    .col(ColumnDef::new(WordExamples::SortOrder).integer().not_null().default(0))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_word_examples_word")
+                            .from(WordExamples::Table, WordExamples::WordId)
+                            .to(Words::Table, Words::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_word_examples_word")
+                    .table(WordExamples::Table)
+                    .col(WordExamples::WordId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(WordExamples::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Words::Table)
+                    .drop_column(WordDetails::PartOfSpeech)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Words::Table)
+                    .drop_column(WordDetails::Phonetic)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum WordDetails {
+    Phonetic,
+    PartOfSpeech,
+}
+
+#[derive(DeriveIden)]
+pub enum WordExamples {
+    Table,
+    Id,
+    WordId,
+    Sentence,
+    Translation,
+    SortOrder,
+}
\ No newline at end of file
@@ -0,0 +1,52 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(DictionaryEntries::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(DictionaryEntries::Id).integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(DictionaryEntries::Headword).string_len(200).not_null())
+                    .col(ColumnDef::new(DictionaryEntries::Phonetic).string_len(200).null())
+                    .col(ColumnDef::new(DictionaryEntries::Translation).text().not_null())
+                    .col(ColumnDef::new(DictionaryEntries::Definition).text().null())
+                    .col(ColumnDef::new(DictionaryEntries::Source).string_len(100).not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_dictionary_entries_headword")
+                    .table(DictionaryEntries::Table)
+                    .col(DictionaryEntries::Headword)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(DictionaryEntries::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum DictionaryEntries {
+    Table,
+    Id,
+    Headword,
+    Phonetic,
+    Translation,
+    Definition,
+    Source,
+}
\ No newline at end of file
@@ -0,0 +1,81 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20260121_000001_create_tables::Users;
+use crate::m20260121_000001_create_tables::Wordbooks;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ImportJobs::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(ImportJobs::Id).integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(ImportJobs::UserId).integer().not_null())
+                    .col(ColumnDef::new(ImportJobs::WordbookId).integer().null())
+                    .col(ColumnDef::new(ImportJobs::Kind).string_len(20).not_null())
+                    .col(ColumnDef::new(ImportJobs::FileName).string_len(255).not_null())
+                    .col(ColumnDef::new(ImportJobs::Status).string_len(20).not_null())
+                    .col(ColumnDef::new(ImportJobs::TotalRows).integer().not_null().default(0))
+                    .col(ColumnDef::new(ImportJobs::ProcessedRows).integer().not_null().default(0))
+                    .col(ColumnDef::new(ImportJobs::Result).text().null())
+                    .col(ColumnDef::new(ImportJobs::Errors).text().null())
+                    .col(ColumnDef::new(ImportJobs::CreatedAt).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(ImportJobs::StartedAt).timestamp_with_time_zone().null())
+                    .col(ColumnDef::new(ImportJobs::FinishedAt).timestamp_with_time_zone().null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_import_jobs_user")
+                            .from(ImportJobs::Table, ImportJobs::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_import_jobs_wordbook")
+                            .from(ImportJobs::Table, ImportJobs::WordbookId)
+                            .to(Wordbooks::Table, Wordbooks::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_import_jobs_user_created")
+                    .table(ImportJobs::Table)
+                    .col(ImportJobs::UserId)
+                    .col(ImportJobs::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(ImportJobs::Table).to_owned()).await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum ImportJobs {
+    Table,
+    Id,
+    UserId,
+    WordbookId,
+    Kind,
+    FileName,
+    Status,
+    TotalRows,
+    ProcessedRows,
+    Result,
+    Errors,
+    CreatedAt,
+    StartedAt,
+    FinishedAt,
+}
\ No newline at end of file
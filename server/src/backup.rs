@@ -0,0 +1,3 @@
+pub mod archive;
+pub mod data;
+pub mod store;
\ No newline at end of file
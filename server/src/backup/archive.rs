@@ -0,0 +1,158 @@
+use std::collections::HashSet;
+use std::io::Cursor;
+use std::io::Read;
+use std::io::Seek;
+use std::io::Write;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use zip::write::SimpleFileOptions;
+use zip::CompressionMethod;
+use zip::ZipArchive;
+use zip::ZipWriter;
+
+use crate::backup::data::AccountBackup;
+use crate::backup::data::BackupManifest;
+use crate::backup::data::BACKUP_FORMAT;
+use crate::backup::data::BACKUP_SCHEMA_VERSION;
+use crate::import::error::ImportError;
+
+const MANIFEST_FILE: &str = "manifest.json";
+const PROFILE_FILE: &str = "profile.json";
+const TAGS_FILE: &str = "tags.json";
+const WORDBOOKS_FILE: &str = "wordbooks.json";
+const CHAPTERS_FILE: &str = "chapters.json";
+const WORDS_FILE: &str = "words.json";
+const EXAMPLES_FILE: &str = "word_examples.json";
+const WORD_TAGS_FILE: &str = "word_tags.json";
+const REVIEW_STATES_FILE: &str = "review_states.json";
+const REVIEW_LOGS_FILE: &str = "review_logs.json";
+
+pub struct BackupArchive;
+
+impl BackupArchive {
+    pub fn write(backup: &AccountBackup) -> Result<Vec<u8>, ImportError> {
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+
+        Self::write_json(&mut writer, options, MANIFEST_FILE, &backup.manifest)?;
+        Self::write_json(&mut writer, options, PROFILE_FILE, &backup.profile)?;
+        Self::write_json(&mut writer, options, TAGS_FILE, &backup.tags)?;
+        Self::write_json(&mut writer, options, WORDBOOKS_FILE, &backup.wordbooks)?;
+        Self::write_json(&mut writer, options, CHAPTERS_FILE, &backup.chapters)?;
+        Self::write_json(&mut writer, options, WORDS_FILE, &backup.words)?;
+        Self::write_json(&mut writer, options, EXAMPLES_FILE, &backup.examples)?;
+        Self::write_json(&mut writer, options, WORD_TAGS_FILE, &backup.word_tags)?;
+        Self::write_json(&mut writer, options, REVIEW_STATES_FILE, &backup.review_states)?;
+        Self::write_json(&mut writer, options, REVIEW_LOGS_FILE, &backup.review_logs)?;
+
+        Ok(writer.finish()?.into_inner())
+    }
+
+    pub fn read(data: &[u8]) -> Result<AccountBackup, ImportError> {
+        let mut archive = ZipArchive::new(Cursor::new(data))?;
+
+        let manifest: BackupManifest = Self::read_json(&mut archive, MANIFEST_FILE)?;
+        if manifest.format != BACKUP_FORMAT {
+            return Err(ImportError::InvalidFormat(format!(
+                "Not an account backup: {}",
+                manifest.format
+            )));
+        }
+
+        // Older schema versions get their own readers here once the format changes.
+        let backup = match manifest.schema_version {
+            BACKUP_SCHEMA_VERSION => Self::read_current(&mut archive, manifest)?,
+            version => {
+                return Err(ImportError::InvalidFormat(format!(
+                    "Unsupported backup schema version {} (expected {})",
+                    version, BACKUP_SCHEMA_VERSION
+                )))
+            }
+        };
+
+        Self::check_references(&backup)?;
+        Ok(backup)
+    }
+
+    fn read_current<R: Read + Seek>(
+        archive: &mut ZipArchive<R>,
+        manifest: BackupManifest,
+    ) -> Result<AccountBackup, ImportError> {
+        Ok(AccountBackup {
+            manifest,
+            profile: Self::read_json(archive, PROFILE_FILE)?,
+            tags: Self::read_json(archive, TAGS_FILE)?,
+            wordbooks: Self::read_json(archive, WORDBOOKS_FILE)?,
+            chapters: Self::read_json(archive, CHAPTERS_FILE)?,
+            words: Self::read_json(archive, WORDS_FILE)?,
+            examples: Self::read_json(archive, EXAMPLES_FILE)?,
+            word_tags: Self::read_json(archive, WORD_TAGS_FILE)?,
+            review_states: Self::read_json(archive, REVIEW_STATES_FILE)?,
+            review_logs: Self::read_json(archive, REVIEW_LOGS_FILE)?,
+        })
+    }
+
+    fn check_references(backup: &AccountBackup) -> Result<(), ImportError> {
+        let tag_ids: HashSet<i32> = backup.tags.iter().map(|t| t.id).collect();
+        let wordbook_ids: HashSet<i32> = backup.wordbooks.iter().map(|w| w.id).collect();
+        let chapter_ids: HashSet<i32> = backup.chapters.iter().map(|c| c.id).collect();
+        let word_ids: HashSet<i32> = backup.words.iter().map(|w| w.id).collect();
+
+        let check = |ids: &HashSet<i32>, id: i32, kind: &str| {
+            if ids.contains(&id) {
+                Ok(())
+            } else {
+                Err(ImportError::InvalidFormat(format!(
+                    "Backup references unknown {} {}",
+                    kind, id
+                )))
+            }
+        };
+
+        for chapter in &backup.chapters {
+            check(&wordbook_ids, chapter.wordbook_id, "wordbook")?;
+        }
+        for word in &backup.words {
+            check(&chapter_ids, word.chapter_id, "chapter")?;
+        }
+        for example in &backup.examples {
+            check(&word_ids, example.word_id, "word")?;
+        }
+        for word_tag in &backup.word_tags {
+            check(&word_ids, word_tag.word_id, "word")?;
+            check(&tag_ids, word_tag.tag_id, "tag")?;
+        }
+        for state in &backup.review_states {
+            check(&word_ids, state.word_id, "word")?;
+        }
+        for log in &backup.review_logs {
+            if let Some(word_id) = log.word_id {
+                check(&word_ids, word_id, "word")?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_json<W: Write + Seek, T: Serialize>(
+        writer: &mut ZipWriter<W>,
+        options: SimpleFileOptions,
+        name: &str,
+        value: &T,
+    ) -> Result<(), ImportError> {
+        writer.start_file(name, options)?;
+        serde_json::to_writer_pretty(&mut *writer, value)?;
+        Ok(())
+    }
+
+    fn read_json<R: Read + Seek, T: DeserializeOwned>(
+        archive: &mut ZipArchive<R>,
+        name: &str,
+    ) -> Result<T, ImportError> {
+        let file = archive
+            .by_name(name)
+            .map_err(|_| ImportError::MissingField(name.to_string()))?;
+        serde_json::from_reader(file)
+            .map_err(|e| ImportError::ParseError(format!("{}: {}", name, e)))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,229 @@
+use sea_orm::prelude::DateTimeWithTimeZone;
+use serde::Deserialize;
+use serde::Serialize;
+
+pub const BACKUP_FORMAT: &str = "plain-word-backup";
+pub const BACKUP_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub format: String,
+    pub schema_version: u32,
+    pub app_version: String,
+    pub exported_at: DateTimeWithTimeZone,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupProfile {
+    pub username: String,
+    pub email: String,
+    pub display_name: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupTag {
+    pub id: i32,
+    pub name: String,
+    pub color: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupWordbook {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub cover_url: Option<String>,
+    pub sort_order: i32,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupChapter {
+    pub id: i32,
+    pub wordbook_id: i32,
+    pub name: String,
+    pub sort_order: i32,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupWord {
+    pub id: i32,
+    pub chapter_id: i32,
+    pub source: String,
+    pub translation: String,
+    pub note: Option<String>,
+    pub phonetic: Option<String>,
+    pub part_of_speech: Option<String>,
+    pub sort_order: i32,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupExample {
+    pub word_id: i32,
+    pub sentence: String,
+    pub translation: Option<String>,
+    pub sort_order: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupWordTag {
+    pub word_id: i32,
+    pub tag_id: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupReviewState {
+    pub word_id: i32,
+    pub ease_factor: f64,
+    pub interval_days: i32,
+    pub repetitions: i32,
+    pub lapses: i32,
+    pub due_at: DateTimeWithTimeZone,
+    pub last_reviewed_at: Option<DateTimeWithTimeZone>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupReviewLog {
+    pub word_id: Option<i32>,
+    pub activity: String,
+    pub grade: Option<i32>,
+    pub is_correct: bool,
+    pub reviewed_at: DateTimeWithTimeZone,
+}
+
+#[derive(Debug, Clone)]
+pub struct AccountBackup {
+    pub manifest: BackupManifest,
+    pub profile: BackupProfile,
+    pub tags: Vec<BackupTag>,
+    pub wordbooks: Vec<BackupWordbook>,
+    pub chapters: Vec<BackupChapter>,
+    pub words: Vec<BackupWord>,
+    pub examples: Vec<BackupExample>,
+    pub word_tags: Vec<BackupWordTag>,
+    pub review_states: Vec<BackupReviewState>,
+    pub review_logs: Vec<BackupReviewLog>,
+}
+
+impl From<entity::users::Model> for BackupProfile {
+    fn from(user: entity::users::Model) -> Self {
+        Self {
+            username: user.username,
+            email: user.email,
+            display_name: user.display_name,
+            created_at: user.created_at,
+        }
+    }
+}
+
+impl From<entity::tags::Model> for BackupTag {
+    fn from(tag: entity::tags::Model) -> Self {
+        Self {
+            id: tag.id,
+            name: tag.name,
+            color: tag.color,
+            created_at: tag.created_at,
+        }
+    }
+}
+
+impl From<entity::wordbooks::Model> for BackupWordbook {
+    fn from(wordbook: entity::wordbooks::Model) -> Self {
+        Self {
+            id: wordbook.id,
+            name: wordbook.name,
+            description: wordbook.description,
+            cover_url: wordbook.cover_url,
+            sort_order: wordbook.sort_order,
+            created_at: wordbook.created_at,
+            updated_at: wordbook.updated_at,
+        }
+    }
+}
+
+impl From<entity::chapters::Model> for BackupChapter {
+    fn from(chapter: entity::chapters::Model) -> Self {
+        Self {
+            id: chapter.id,
+            wordbook_id: chapter.wordbook_id,
+            name: chapter.name,
+            sort_order: chapter.sort_order,
+            created_at: chapter.created_at,
+            updated_at: chapter.updated_at,
+        }
+    }
+}
+
+impl From<entity::words::Model> for BackupWord {
+    fn from(word: entity::words::Model) -> Self {
+        Self {
+            id: word.id,
+            chapter_id: word.chapter_id,
+            source: word.source,
+            translation: word.translation,
+            note: word.note,
+            phonetic: word.phonetic,
+            part_of_speech: word.part_of_speech,
+            sort_order: word.sort_order,
+            created_at: word.created_at,
+            updated_at: word.updated_at,
+        }
+    }
+}
+
+impl From<entity::word_examples::Model> for BackupExample {
+    fn from(example: entity::word_examples::Model) -> Self {
+        Self {
+            word_id: example.word_id,
+            sentence: example.sentence,
+            translation: example.translation,
+            sort_order: example.sort_order,
+        }
+    }
+}
+
+impl From<entity::word_tags::Model> for BackupWordTag {
+    fn from(word_tag: entity::word_tags::Model) -> Self {
+        Self {
+            word_id: word_tag.word_id,
+            tag_id: word_tag.tag_id,
+        }
+    }
+}
+
+impl From<entity::review_states::Model> for BackupReviewState {
+    fn from(state: entity::review_states::Model) -> Self {
+        Self {
+            word_id: state.word_id,
+            ease_factor: state.ease_factor,
+            interval_days: state.interval_days,
+            repetitions: state.repetitions,
+            lapses: state.lapses,
+            due_at: state.due_at,
+            last_reviewed_at: state.last_reviewed_at,
+            created_at: state.created_at,
+            updated_at: state.updated_at,
+        }
+    }
+}
+
+impl From<entity::review_logs::Model> for BackupReviewLog {
+    fn from(log: entity::review_logs::Model) -> Self {
+        Self {
+            word_id: log.word_id,
+            activity: log.activity,
+            grade: log.grade,
+            is_correct: log.is_correct,
+            reviewed_at: log.reviewed_at,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,337 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+use sea_orm::sea_query::SelectStatement;
+use sea_orm::ActiveModelTrait;
+use sea_orm::ActiveValue::NotSet;
+use sea_orm::ActiveValue::Unchanged;
+use sea_orm::ColumnTrait;
+use sea_orm::ConnectionTrait;
+use sea_orm::DatabaseConnection;
+use sea_orm::DbErr;
+use sea_orm::EntityTrait;
+use sea_orm::IntoActiveModel;
+use sea_orm::QueryFilter;
+use sea_orm::QueryOrder;
+use sea_orm::QuerySelect;
+use sea_orm::QueryTrait;
+use sea_orm::Set;
+use sea_orm::TransactionTrait;
+use serde::Serialize;
+
+use crate::backup::data::AccountBackup;
+use crate::backup::data::BackupManifest;
+use crate::backup::data::BACKUP_FORMAT;
+use crate::backup::data::BACKUP_SCHEMA_VERSION;
+
+const INSERT_BATCH_SIZE: usize = 500;
+
+#[derive(Debug, Default, Serialize)]
+pub struct RestoreResult {
+    pub tags_created: usize,
+    pub tags_matched: usize,
+    pub wordbooks_created: usize,
+    pub chapters_created: usize,
+    pub words_created: usize,
+    pub review_states_restored: usize,
+    pub review_logs_restored: usize,
+}
+
+pub struct BackupStore;
+
+impl BackupStore {
+    pub async fn collect(db: &DatabaseConnection, user_id: i32) -> Result<AccountBackup, DbErr> {
+        let user = entity::users::Entity::find_by_id(user_id)
+            .one(db)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("User not found".to_string()))?;
+
+        let tags = entity::tags::Entity::find()
+            .filter(entity::tags::Column::UserId.eq(user_id))
+            .order_by_asc(entity::tags::Column::Id)
+            .all(db)
+            .await?;
+        let wordbooks = entity::wordbooks::Entity::find()
+            .filter(entity::wordbooks::Column::UserId.eq(user_id))
+            .order_by_asc(entity::wordbooks::Column::SortOrder)
+            .order_by_asc(entity::wordbooks::Column::Id)
+            .all(db)
+            .await?;
+        let chapters = entity::chapters::Entity::find()
+            .filter(entity::chapters::Column::WordbookId.in_subquery(Self::wordbook_ids(user_id)))
+            .order_by_asc(entity::chapters::Column::WordbookId)
+            .order_by_asc(entity::chapters::Column::SortOrder)
+            .order_by_asc(entity::chapters::Column::Id)
+            .all(db)
+            .await?;
+        let words = entity::words::Entity::find()
+            .filter(entity::words::Column::ChapterId.in_subquery(Self::chapter_ids(user_id)))
+            .order_by_asc(entity::words::Column::ChapterId)
+            .order_by_asc(entity::words::Column::SortOrder)
+            .order_by_asc(entity::words::Column::Id)
+            .all(db)
+            .await?;
+        let examples = entity::word_examples::Entity::find()
+            .filter(entity::word_examples::Column::WordId.in_subquery(Self::word_ids(user_id)))
+            .order_by_asc(entity::word_examples::Column::WordId)
+            .order_by_asc(entity::word_examples::Column::SortOrder)
+            .all(db)
+            .await?;
+        let word_tags = entity::word_tags::Entity::find()
+            .filter(entity::word_tags::Column::WordId.in_subquery(Self::word_ids(user_id)))
+            .order_by_asc(entity::word_tags::Column::WordId)
+            .order_by_asc(entity::word_tags::Column::TagId)
+            .all(db)
+            .await?;
+        let review_states = entity::review_states::Entity::find()
+            .filter(entity::review_states::Column::UserId.eq(user_id))
+            .order_by_asc(entity::review_states::Column::Id)
+            .all(db)
+            .await?;
+        let review_logs = entity::review_logs::Entity::find()
+            .filter(entity::review_logs::Column::UserId.eq(user_id))
+            .order_by_asc(entity::review_logs::Column::Id)
+            .all(db)
+            .await?;
+
+        Ok(AccountBackup {
+            manifest: BackupManifest {
+                format: BACKUP_FORMAT.to_string(),
+                schema_version: BACKUP_SCHEMA_VERSION,
+                app_version: env!("CARGO_PKG_VERSION").to_string(),
+                exported_at: Utc::now().fixed_offset(),
+            },
+            profile: user.into(),
+            tags: tags.into_iter().map(Into::into).collect(),
+            wordbooks: wordbooks.into_iter().map(Into::into).collect(),
+            chapters: chapters.into_iter().map(Into::into).collect(),
+            words: words.into_iter().map(Into::into).collect(),
+            examples: examples.into_iter().map(Into::into).collect(),
+            word_tags: word_tags.into_iter().map(Into::into).collect(),
+            review_states: review_states.into_iter().map(Into::into).collect(),
+            review_logs: review_logs.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    // Restored wordbooks are appended after the account's own; tags are merged by name.
+    pub async fn restore(
+        db: &DatabaseConnection,
+        user_id: i32,
+        backup: AccountBackup,
+    ) -> Result<RestoreResult, DbErr> {
+        let txn = db.begin().await?;
+        let mut result = RestoreResult::default();
+
+        let user = entity::users::Entity::find_by_id(user_id)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("User not found".to_string()))?;
+        if user.display_name.is_none() && backup.profile.display_name.is_some() {
+            entity::users::ActiveModel {
+                id: Unchanged(user_id),
+                display_name: Set(backup.profile.display_name),
+                updated_at: Set(Utc::now().fixed_offset()),
+                ..Default::default()
+            }
+            .update(&txn)
+            .await?;
+        }
+
+        let mut existing_tags: HashMap<String, i32> = entity::tags::Entity::find()
+            .filter(entity::tags::Column::UserId.eq(user_id))
+            .all(&txn)
+            .await?
+            .into_iter()
+            .map(|tag| (tag.name, tag.id))
+            .collect();
+        let mut tag_ids = HashMap::new();
+        for tag in backup.tags {
+            let id = match existing_tags.get(&tag.name) {
+                Some(id) => {
+                    result.tags_matched += 1;
+                    *id
+                }
+                None => {
+                    result.tags_created += 1;
+                    let saved = entity::tags::ActiveModel {
+                        id: NotSet,
+                        user_id: Set(user_id),
+                        name: Set(tag.name),
+                        color: Set(tag.color),
+                        created_at: Set(tag.created_at),
+                    }
+                    .insert(&txn)
+                    .await?;
+                    existing_tags.insert(saved.name, saved.id);
+                    saved.id
+                }
+            };
+            tag_ids.insert(tag.id, id);
+        }
+
+        let next_order = entity::wordbooks::Entity::find()
+            .filter(entity::wordbooks::Column::UserId.eq(user_id))
+            .order_by_desc(entity::wordbooks::Column::SortOrder)
+            .one(&txn)
+            .await?
+            .map(|w| w.sort_order + 1)
+            .unwrap_or(0);
+        let mut wordbooks = backup.wordbooks;
+        wordbooks.sort_by_key(|w| (w.sort_order, w.id));
+        let mut wordbook_ids = HashMap::new();
+        for (idx, wordbook) in wordbooks.into_iter().enumerate() {
+            let saved = entity::wordbooks::ActiveModel {
+                id: NotSet,
+                user_id: Set(user_id),
+                name: Set(wordbook.name),
+                description: Set(wordbook.description),
+                cover_url: Set(wordbook.cover_url),
+                sort_order: Set(next_order + idx as i32),
+                created_at: Set(wordbook.created_at),
+                updated_at: Set(wordbook.updated_at),
+            }
+            .insert(&txn)
+            .await?;
+            wordbook_ids.insert(wordbook.id, saved.id);
+        }
+        result.wordbooks_created = wordbook_ids.len();
+
+        let mut chapter_ids = HashMap::new();
+        for chapter in backup.chapters {
+            let saved = entity::chapters::ActiveModel {
+                id: NotSet,
+                wordbook_id: Set(Self::remap(&wordbook_ids, chapter.wordbook_id)?),
+                name: Set(chapter.name),
+                sort_order: Set(chapter.sort_order),
+                created_at: Set(chapter.created_at),
+                updated_at: Set(chapter.updated_at),
+            }
+            .insert(&txn)
+            .await?;
+            chapter_ids.insert(chapter.id, saved.id);
+        }
+        result.chapters_created = chapter_ids.len();
+
+        let mut word_ids = HashMap::new();
+        for word in backup.words {
+            let saved = entity::words::ActiveModel {
+                id: NotSet,
+                chapter_id: Set(Self::remap(&chapter_ids, word.chapter_id)?),
+                source: Set(word.source),
+                translation: Set(word.translation),
+                note: Set(word.note),
+                sort_order: Set(word.sort_order),
+                created_at: Set(word.created_at),
+                updated_at: Set(word.updated_at),
+                phonetic: Set(word.phonetic),
+                part_of_speech: Set(word.part_of_speech),
+            }
+            .insert(&txn)
+            .await?;
+            word_ids.insert(word.id, saved.id);
+        }
+        result.words_created = word_ids.len();
+
+        let mut examples = Vec::with_capacity(backup.examples.len());
+        for example in backup.examples {
+            examples.push(entity::word_examples::ActiveModel {
+                id: NotSet,
+                word_id: Set(Self::remap(&word_ids, example.word_id)?),
+                sentence: Set(example.sentence),
+                translation: Set(example.translation),
+                sort_order: Set(example.sort_order),
+            });
+        }
+        Self::insert_all(&txn, examples).await?;
+
+        let mut word_tags = Vec::with_capacity(backup.word_tags.len());
+        for word_tag in backup.word_tags {
+            word_tags.push(entity::word_tags::ActiveModel {
+                word_id: Set(Self::remap(&word_ids, word_tag.word_id)?),
+                tag_id: Set(Self::remap(&tag_ids, word_tag.tag_id)?),
+            });
+        }
+        Self::insert_all(&txn, word_tags).await?;
+
+        let mut review_states = Vec::with_capacity(backup.review_states.len());
+        for state in backup.review_states {
+            review_states.push(entity::review_states::ActiveModel {
+                id: NotSet,
+                user_id: Set(user_id),
+                word_id: Set(Self::remap(&word_ids, state.word_id)?),
+                ease_factor: Set(state.ease_factor),
+                interval_days: Set(state.interval_days),
+                repetitions: Set(state.repetitions),
+                lapses: Set(state.lapses),
+                due_at: Set(state.due_at),
+                last_reviewed_at: Set(state.last_reviewed_at),
+                created_at: Set(state.created_at),
+                updated_at: Set(state.updated_at),
+            });
+        }
+        result.review_states_restored = review_states.len();
+        Self::insert_all(&txn, review_states).await?;
+
+        let mut review_logs = Vec::with_capacity(backup.review_logs.len());
+        for log in backup.review_logs {
+            review_logs.push(entity::review_logs::ActiveModel {
+                id: NotSet,
+                user_id: Set(user_id),
+                word_id: Set(log.word_id.map(|id| Self::remap(&word_ids, id)).transpose()?),
+                activity: Set(log.activity),
+                grade: Set(log.grade),
+                is_correct: Set(log.is_correct),
+                reviewed_at: Set(log.reviewed_at),
+            });
+        }
+        result.review_logs_restored = review_logs.len();
+        Self::insert_all(&txn, review_logs).await?;
+
+        txn.commit().await?;
+        Ok(result)
+    }
+
+    fn remap(ids: &HashMap<i32, i32>, id: i32) -> Result<i32, DbErr> {
+        ids.get(&id)
+            .copied()
+            .ok_or_else(|| DbErr::RecordNotFound(format!("Backup record {} was not restored", id)))
+    }
+
+    async fn insert_all<C, A>(db: &C, models: Vec<A>) -> Result<(), DbErr>
+    where
+        C: ConnectionTrait,
+        A: ActiveModelTrait + Send,
+        <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
+    {
+        for chunk in models.chunks(INSERT_BATCH_SIZE) {
+            A::Entity::insert_many(chunk.to_vec())
+                .exec_without_returning(db)
+                .await?;
+        }
+        Ok(())
+    }
+
+    fn wordbook_ids(user_id: i32) -> SelectStatement {
+        entity::wordbooks::Entity::find()
+            .select_only()
+            .column(entity::wordbooks::Column::Id)
+            .filter(entity::wordbooks::Column::UserId.eq(user_id))
+            .into_query()
+    }
+
+    fn chapter_ids(user_id: i32) -> SelectStatement {
+        entity::chapters::Entity::find()
+            .select_only()
+            .column(entity::chapters::Column::Id)
+            .filter(entity::chapters::Column::WordbookId.in_subquery(Self::wordbook_ids(user_id)))
+            .into_query()
+    }
+
+    fn word_ids(user_id: i32) -> SelectStatement {
+        entity::words::Entity::find()
+            .select_only()
+            .column(entity::words::Column::Id)
+            .filter(entity::words::Column::ChapterId.in_subquery(Self::chapter_ids(user_id)))
+            .into_query()
+    }
+}
\ No newline at end of file
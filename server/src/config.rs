@@ -1,9 +1,12 @@
 use std::env;
+use std::path::PathBuf;
 
 pub struct Config {
     pub database_url: String,
     pub server_host: String,
     pub server_port: u16,
+    pub dictionary_path: Option<String>,
+    pub pdf_font_paths: Vec<PathBuf>,
 }
 
 impl Config {
@@ -18,6 +21,10 @@ impl Config {
                 .ok()
                 .and_then(|p| p.parse().ok())
                 .unwrap_or(3000),
+            dictionary_path: env::var("DICTIONARY_PATH").ok().filter(|p| !p.is_empty()),
+            pdf_font_paths: env::var_os("PDF_FONT_PATH")
+                .map(|paths| env::split_paths(&paths).collect())
+                .unwrap_or_default(),
         }
     }
 }
\ No newline at end of file
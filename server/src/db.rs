@@ -1,5 +1,11 @@
+use sea_orm::ConnectOptions;
 use sea_orm::Database;
 use sea_orm::DatabaseConnection;
+use sea_orm::DbErr;
+use sea_orm::RuntimeErr;
+use sea_orm::SqlxSqliteConnector;
+use sqlx::sqlite::SqliteJournalMode;
+use sqlx::sqlite::SqlitePoolOptions;
 
 use crate::config::Config;
 use crate::error::AppError;
@@ -7,9 +13,24 @@ use crate::error::AppError;
 pub struct DbPool;
 
 impl DbPool {
+    // WAL lets a long read, such as an export, run without locking out writers.
     pub async fn connect(config: &Config) -> Result<DatabaseConnection, AppError> {
-        Database::connect(&config.database_url)
+        let mut options = ConnectOptions::new(&config.database_url);
+        options.map_sqlx_sqlite_opts(|opts| opts.journal_mode(SqliteJournalMode::Wal));
+        Database::connect(options)
             .await
             .map_err(AppError::Database)
     }
+
+    // A connection of its own for reads held open while a client downloads, so the
+    // shared pool's single connection stays free for other requests.
+    pub async fn dedicated(db: &DatabaseConnection) -> Result<DatabaseConnection, DbErr> {
+        let options = db.get_sqlite_connection_pool().connect_options();
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with((*options).clone())
+            .await
+            .map_err(|e| DbErr::Conn(RuntimeErr::SqlxError(e)))?;
+        Ok(SqlxSqliteConnector::from_sqlx_sqlite_pool(pool))
+    }
 }
\ No newline at end of file
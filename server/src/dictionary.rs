@@ -0,0 +1,5 @@
+pub mod ecdict;
+pub mod entry;
+pub mod loader;
+pub mod lookup;
+pub mod stardict;
\ No newline at end of file
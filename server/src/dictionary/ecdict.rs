@@ -0,0 +1,79 @@
+use std::io::Read;
+
+use crate::dictionary::entry::DictionaryEntry;
+use crate::import::error::ImportError;
+
+const COLUMN_WORD: &str = "word";
+const COLUMN_PHONETIC: &str = "phonetic";
+const COLUMN_DEFINITION: &str = "definition";
+const COLUMN_TRANSLATION: &str = "translation";
+const ESCAPED_NEWLINE: &str = "\\n";
+const BOM: char = '\u{feff}';
+
+pub struct EcdictParser;
+
+impl EcdictParser {
+    pub fn parse<R: Read>(reader: R) -> Result<Vec<DictionaryEntry>, ImportError> {
+        let mut entries = Vec::new();
+        Self::read_each(reader, |entry| {
+            entries.push(entry);
+            true
+        })?;
+        Ok(entries)
+    }
+
+    // Hands each entry to `emit` as it is read, so a full ECDICT is never held in memory.
+    // Reading stops early when `emit` returns false.
+    pub fn read_each<R: Read>(
+        reader: R,
+        mut emit: impl FnMut(DictionaryEntry) -> bool,
+    ) -> Result<(), ImportError> {
+        let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(reader);
+
+        let headers = reader.headers()?.clone();
+        let column = |name: &str| {
+            headers
+                .iter()
+                .position(|h| h.trim_start_matches(BOM).trim().eq_ignore_ascii_case(name))
+        };
+
+        let word_idx =
+            column(COLUMN_WORD).ok_or_else(|| ImportError::MissingField(COLUMN_WORD.to_string()))?;
+        let translation_idx = column(COLUMN_TRANSLATION)
+            .ok_or_else(|| ImportError::MissingField(COLUMN_TRANSLATION.to_string()))?;
+        let phonetic_idx = column(COLUMN_PHONETIC);
+        let definition_idx = column(COLUMN_DEFINITION);
+
+        for record in reader.records() {
+            let record = record?;
+            let field = |idx: Option<usize>| {
+                idx.and_then(|idx| record.get(idx))
+                    .map(Self::unescape)
+                    .filter(|value| !value.trim().is_empty())
+            };
+
+            let definition = field(definition_idx);
+            let translation = field(Some(translation_idx))
+                .or_else(|| definition.clone())
+                .unwrap_or_default();
+
+            let Some(entry) = DictionaryEntry::new(
+                record.get(word_idx).unwrap_or_default(),
+                field(phonetic_idx),
+                translation,
+                definition,
+            ) else {
+                continue;
+            };
+            if !emit(entry) {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn unescape(value: &str) -> String {
+        value.replace(ESCAPED_NEWLINE, "\n")
+    }
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+pub struct DictionaryEntry {
+    pub headword: String,
+    pub phonetic: Option<String>,
+    pub translation: String,
+    pub definition: Option<String>,
+}
+
+impl DictionaryEntry {
+    pub fn normalize_headword(word: &str) -> String {
+        word.trim().to_lowercase()
+    }
+
+    pub fn new(
+        headword: &str,
+        phonetic: Option<String>,
+        translation: String,
+        definition: Option<String>,
+    ) -> Option<Self> {
+        let headword = Self::normalize_headword(headword);
+        let translation = translation.trim().to_string();
+        if headword.is_empty() || translation.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            headword,
+            phonetic: phonetic
+                .map(|p| p.trim().to_string())
+                .filter(|p| !p.is_empty()),
+            translation,
+            definition: definition
+                .map(|d| d.trim().to_string())
+                .filter(|d| !d.is_empty()),
+        })
+    }
+}
\ No newline at end of file
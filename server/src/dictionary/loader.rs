@@ -0,0 +1,140 @@
+use std::fs::File;
+use std::mem;
+use std::path::Path;
+use std::path::PathBuf;
+
+use sea_orm::sea_query::OnConflict;
+use sea_orm::ActiveValue::NotSet;
+use sea_orm::ColumnTrait;
+use sea_orm::DatabaseConnection;
+use sea_orm::DbErr;
+use sea_orm::EntityTrait;
+use sea_orm::PaginatorTrait;
+use sea_orm::QueryFilter;
+use sea_orm::Set;
+use tokio::sync::mpsc;
+
+use crate::dictionary::ecdict::EcdictParser;
+use crate::dictionary::entry::DictionaryEntry;
+use crate::dictionary::stardict::StarDictParser;
+use crate::error::AppError;
+use crate::import::error::ImportError;
+
+const INSERT_BATCH_SIZE: usize = 500;
+// Parsed batches waiting for insertion.
+const CHANNEL_CAPACITY: usize = 4;
+const EXTENSION_CSV: &str = "csv";
+const EXTENSION_IFO: &str = "ifo";
+
+pub struct DictionaryLoader;
+
+impl DictionaryLoader {
+    pub async fn load_if_empty(db: &DatabaseConnection, path: &str) -> Result<u64, AppError> {
+        let existing = entity::dictionary_entries::Entity::find().count(db).await?;
+        if existing > 0 {
+            return Ok(existing);
+        }
+        Self::load(db, path).await
+    }
+
+    // Entries are parsed on a blocking thread and inserted a batch per statement, so the
+    // pool's single SQLite connection is free for requests between batches. A failed load
+    // removes what it inserted, leaving the table empty for the next start to retry.
+    pub async fn load(db: &DatabaseConnection, path: &str) -> Result<u64, AppError> {
+        let path = PathBuf::from(path);
+        let source = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("dictionary")
+            .to_string();
+
+        let (sender, mut receiver) = mpsc::channel(CHANNEL_CAPACITY);
+        let parse_path = path.clone();
+        let parser = tokio::task::spawn_blocking(move || Self::parse(&parse_path, sender));
+
+        let mut inserted = Ok(());
+        while let Some(batch) = receiver.recv().await {
+            inserted = Self::insert_batch(db, &source, batch).await;
+            if inserted.is_err() {
+                break;
+            }
+        }
+        // Dropping the receiver stops the parser after a failed insert.
+        drop(receiver);
+        let parsed = parser
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?
+            .map_err(|e| AppError::Internal(format!("{}: {}", path.display(), e)));
+
+        if let Err(e) = inserted.map_err(AppError::from).and(parsed) {
+            entity::dictionary_entries::Entity::delete_many()
+                .filter(entity::dictionary_entries::Column::Source.eq(source))
+                .exec(db)
+                .await?;
+            return Err(e);
+        }
+
+        Ok(entity::dictionary_entries::Entity::find().count(db).await?)
+    }
+
+    async fn insert_batch(
+        db: &DatabaseConnection,
+        source: &str,
+        batch: Vec<DictionaryEntry>,
+    ) -> Result<(), DbErr> {
+        let models = batch
+            .into_iter()
+            .map(|entry| entity::dictionary_entries::ActiveModel {
+                id: NotSet,
+                headword: Set(entry.headword),
+                phonetic: Set(entry.phonetic),
+                translation: Set(entry.translation),
+                definition: Set(entry.definition),
+                source: Set(source.to_string()),
+            });
+
+        entity::dictionary_entries::Entity::insert_many(models)
+            .on_conflict(
+                OnConflict::column(entity::dictionary_entries::Column::Headword)
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .do_nothing()
+            .exec(db)
+            .await?;
+        Ok(())
+    }
+
+    fn parse(path: &Path, sender: mpsc::Sender<Vec<DictionaryEntry>>) -> Result<(), ImportError> {
+        let ext = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+
+        let mut batch = Vec::with_capacity(INSERT_BATCH_SIZE);
+        let mut emit = |entry| {
+            batch.push(entry);
+            if batch.len() < INSERT_BATCH_SIZE {
+                return true;
+            }
+            sender.blocking_send(mem::take(&mut batch)).is_ok()
+        };
+
+        match ext.as_str() {
+            EXTENSION_CSV => EcdictParser::read_each(File::open(path)?, &mut emit)?,
+            EXTENSION_IFO => StarDictParser::read_each(path, &mut emit)?,
+            _ => {
+                return Err(ImportError::InvalidFormat(format!(
+                    "Unsupported dictionary format: {}",
+                    ext
+                )))
+            }
+        }
+
+        if !batch.is_empty() {
+            let _ = sender.blocking_send(batch);
+        }
+        Ok(())
+    }
+}
\ No newline at end of file
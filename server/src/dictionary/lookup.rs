@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use sea_orm::ColumnTrait;
+use sea_orm::ConnectionTrait;
+use sea_orm::DbErr;
+use sea_orm::EntityTrait;
+use sea_orm::QueryFilter;
+
+use crate::dictionary::entry::DictionaryEntry;
+use crate::import::data::ImportWord;
+
+const LOOKUP_BATCH_SIZE: usize = 500;
+const MAX_TRANSLATION_CHARS: usize = 500;
+const TRANSLATION_SEPARATOR: &str = "; ";
+
+pub struct DictionaryLookup;
+
+impl DictionaryLookup {
+    pub async fn find<C: ConnectionTrait>(
+        db: &C,
+        word: &str,
+    ) -> Result<Option<entity::dictionary_entries::Model>, DbErr> {
+        entity::dictionary_entries::Entity::find()
+            .filter(
+                entity::dictionary_entries::Column::Headword
+                    .eq(DictionaryEntry::normalize_headword(word)),
+            )
+            .one(db)
+            .await
+    }
+
+    pub async fn find_many<C: ConnectionTrait>(
+        db: &C,
+        words: &[&str],
+    ) -> Result<HashMap<String, entity::dictionary_entries::Model>, DbErr> {
+        let mut headwords: Vec<String> = words
+            .iter()
+            .map(|word| DictionaryEntry::normalize_headword(word))
+            .filter(|word| !word.is_empty())
+            .collect();
+        headwords.sort();
+        headwords.dedup();
+
+        let mut found = HashMap::new();
+        for chunk in headwords.chunks(LOOKUP_BATCH_SIZE) {
+            for entry in entity::dictionary_entries::Entity::find()
+                .filter(entity::dictionary_entries::Column::Headword.is_in(chunk.to_vec()))
+                .all(db)
+                .await?
+            {
+                found.insert(entry.headword.clone(), entry);
+            }
+        }
+        Ok(found)
+    }
+
+    pub async fn fill_words<C: ConnectionTrait>(
+        db: &C,
+        words: &mut [ImportWord],
+    ) -> Result<usize, DbErr> {
+        let missing: Vec<&str> = words
+            .iter()
+            .filter(|word| word.translation.trim().is_empty() || word.phonetic.is_none())
+            .map(|word| word.source.as_str())
+            .collect();
+        if missing.is_empty() {
+            return Ok(0);
+        }
+
+        let found = Self::find_many(db, &missing).await?;
+        let mut filled = 0;
+        for word in words.iter_mut() {
+            if let Some(entry) = found.get(&DictionaryEntry::normalize_headword(&word.source)) {
+                filled += usize::from(Self::fill_word(word, &entry.translation, &entry.phonetic));
+            }
+        }
+        Ok(filled)
+    }
+
+    pub fn fill_words_from(entries: &HashMap<String, DictionaryEntry>, words: &mut [ImportWord]) -> usize {
+        let mut filled = 0;
+        for word in words.iter_mut() {
+            if let Some(entry) = entries.get(&DictionaryEntry::normalize_headword(&word.source)) {
+                filled += usize::from(Self::fill_word(word, &entry.translation, &entry.phonetic));
+            }
+        }
+        filled
+    }
+
+    fn fill_word(word: &mut ImportWord, translation: &str, phonetic: &Option<String>) -> bool {
+        if word.phonetic.is_none() {
+            word.phonetic = phonetic.clone();
+        }
+        if word.translation.trim().is_empty() {
+            word.translation = Self::summary(translation);
+            return true;
+        }
+        false
+    }
+
+    pub fn summary(translation: &str) -> String {
+        translation
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join(TRANSLATION_SEPARATOR)
+            .chars()
+            .take(MAX_TRANSLATION_CHARS)
+            .collect()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+use flate2::read::GzDecoder;
+
+use crate::dictionary::entry::DictionaryEntry;
+use crate::import::error::ImportError;
+use crate::markup::Markup;
+
+const IFO_MAGIC: &str = "StarDict's dict ifo file";
+const KEY_SAME_TYPE_SEQUENCE: &str = "sametypesequence";
+const KEY_IDX_OFFSET_BITS: &str = "idxoffsetbits";
+const TYPE_PHONETIC: u8 = b't';
+const TEXT_TYPES: &[u8] = b"mlgxh";
+const MARKUP_TYPES: &[u8] = b"gxh";
+
+struct IndexEntry {
+    word: String,
+    offset: usize,
+    size: usize,
+}
+
+pub struct StarDictParser;
+
+impl StarDictParser {
+    // Articles are decoded one at a time and handed to `emit`; reading stops early when
+    // it returns false.
+    pub fn read_each(
+        ifo_path: &Path,
+        mut emit: impl FnMut(DictionaryEntry) -> bool,
+    ) -> Result<(), ImportError> {
+        let info = Self::read_info(&fs::read_to_string(ifo_path)?)?;
+        let offset_bits = match info.get(KEY_IDX_OFFSET_BITS).map(String::as_str) {
+            Some("64") => 64,
+            _ => 32,
+        };
+        let sequence = info.get(KEY_SAME_TYPE_SEQUENCE).map(|s| s.as_bytes());
+
+        let index = Self::read_index(&Self::read_companion(ifo_path, "idx")?, offset_bits)?;
+        let dict = Self::read_companion(ifo_path, "dict")?;
+
+        for item in index {
+            let article = dict
+                .get(item.offset..item.offset + item.size)
+                .ok_or_else(|| {
+                    ImportError::InvalidFormat(format!("Article for '{}' is out of range", item.word))
+                })?;
+            let (phonetic, translation) = Self::parse_article(article, sequence);
+            let Some(entry) = DictionaryEntry::new(&item.word, phonetic, translation, None) else {
+                continue;
+            };
+            if !emit(entry) {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn read_info(content: &str) -> Result<HashMap<String, String>, ImportError> {
+        let mut lines = content.lines();
+        if lines.next().map(str::trim) != Some(IFO_MAGIC) {
+            return Err(ImportError::InvalidFormat("Not a StarDict .ifo file".to_string()));
+        }
+
+        Ok(lines
+            .filter_map(|line| line.split_once('='))
+            .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+            .collect())
+    }
+
+    fn read_companion(ifo_path: &Path, extension: &str) -> Result<Vec<u8>, ImportError> {
+        let plain = ifo_path.with_extension(extension);
+        if plain.exists() {
+            return Ok(fs::read(plain)?);
+        }
+
+        for compressed in [format!("{}.dz", extension), format!("{}.gz", extension)] {
+            let path = ifo_path.with_extension(compressed);
+            if path.exists() {
+                let mut data = Vec::new();
+                GzDecoder::new(fs::File::open(path)?).read_to_end(&mut data)?;
+                return Ok(data);
+            }
+        }
+
+        Err(ImportError::InvalidFormat(format!(
+            "Missing StarDict .{} file next to {}",
+            extension,
+            ifo_path.display()
+        )))
+    }
+
+    fn read_index(data: &[u8], offset_bits: u8) -> Result<Vec<IndexEntry>, ImportError> {
+        let offset_len = usize::from(offset_bits / 8);
+        let mut entries = Vec::new();
+        let mut pos = 0;
+
+        while pos < data.len() {
+            let end = data[pos..]
+                .iter()
+                .position(|b| *b == 0)
+                .map(|len| pos + len)
+                .ok_or_else(|| ImportError::InvalidFormat("Truncated StarDict index".to_string()))?;
+            let word = String::from_utf8_lossy(&data[pos..end]).into_owned();
+            pos = end + 1;
+
+            let offset = Self::read_be(data, pos, offset_len)?;
+            pos += offset_len;
+            let size = Self::read_be(data, pos, 4)?;
+            pos += 4;
+
+            entries.push(IndexEntry { word, offset, size });
+        }
+
+        Ok(entries)
+    }
+
+    fn read_be(data: &[u8], pos: usize, len: usize) -> Result<usize, ImportError> {
+        let bytes = data
+            .get(pos..pos + len)
+            .ok_or_else(|| ImportError::InvalidFormat("Truncated StarDict index".to_string()))?;
+        Ok(bytes.iter().fold(0usize, |acc, b| (acc << 8) | usize::from(*b)))
+    }
+
+    fn parse_article(data: &[u8], sequence: Option<&[u8]>) -> (Option<String>, String) {
+        let mut fields: Vec<(u8, &[u8])> = Vec::new();
+        let mut pos = 0;
+
+        match sequence {
+            Some(types) => {
+                for (idx, kind) in types.iter().enumerate() {
+                    let is_last = idx + 1 == types.len();
+                    let Some((value, next)) = Self::read_field(data, pos, *kind, is_last) else {
+                        break;
+                    };
+                    fields.push((*kind, value));
+                    pos = next;
+                }
+            }
+            None => {
+                while pos < data.len() {
+                    let kind = data[pos];
+                    let Some((value, next)) = Self::read_field(data, pos + 1, kind, false) else {
+                        break;
+                    };
+                    fields.push((kind, value));
+                    pos = next;
+                }
+            }
+        }
+
+        let mut phonetic = None;
+        let mut texts = Vec::new();
+        for (kind, value) in fields {
+            let text = String::from_utf8_lossy(value);
+            if kind == TYPE_PHONETIC {
+                phonetic = Some(text.into_owned());
+            } else if MARKUP_TYPES.contains(&kind) {
+                texts.push(Markup::strip(&text));
+            } else if TEXT_TYPES.contains(&kind) {
+                texts.push(text.into_owned());
+            }
+        }
+
+        (phonetic, texts.join("\n"))
+    }
+
+    fn read_field(data: &[u8], pos: usize, kind: u8, is_last: bool) -> Option<(&[u8], usize)> {
+        let rest = data.get(pos..)?;
+        if is_last {
+            return Some((rest, data.len()));
+        }
+
+        if kind.is_ascii_lowercase() {
+            let len = rest.iter().position(|b| *b == 0).unwrap_or(rest.len());
+            Some((&rest[..len], pos + len + 1))
+        } else {
+            let size = Self::read_be(data, pos, 4).ok()?;
+            let value = data.get(pos + 4..pos + 4 + size)?;
+            Some((value, pos + 4 + size))
+        }
+    }
+}
\ No newline at end of file
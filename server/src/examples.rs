@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use sea_orm::ActiveValue::NotSet;
+use sea_orm::ColumnTrait;
+use sea_orm::ConnectionTrait;
+use sea_orm::DbErr;
+use sea_orm::EntityTrait;
+use sea_orm::QueryFilter;
+use sea_orm::QueryOrder;
+use sea_orm::Set;
+
+use crate::import::data::ImportExample;
+
+const INSERT_BATCH_SIZE: usize = 500;
+
+pub struct ExampleStore;
+
+impl ExampleStore {
+    pub async fn load<C: ConnectionTrait>(
+        db: &C,
+        word_ids: Vec<i32>,
+    ) -> Result<HashMap<i32, Vec<entity::word_examples::Model>>, DbErr> {
+        let mut examples: HashMap<i32, Vec<entity::word_examples::Model>> = HashMap::new();
+        if word_ids.is_empty() {
+            return Ok(examples);
+        }
+
+        for example in entity::word_examples::Entity::find()
+            .filter(entity::word_examples::Column::WordId.is_in(word_ids))
+            .order_by_asc(entity::word_examples::Column::WordId)
+            .order_by_asc(entity::word_examples::Column::SortOrder)
+            .all(db)
+            .await?
+        {
+            examples.entry(example.word_id).or_default().push(example);
+        }
+        Ok(examples)
+    }
+
+    pub async fn insert<C: ConnectionTrait>(
+        db: &C,
+        word_id: i32,
+        examples: Vec<ImportExample>,
+    ) -> Result<(), DbErr> {
+        Self::insert_many(db, [(word_id, examples)]).await
+    }
+
+    pub async fn insert_many<C: ConnectionTrait>(
+        db: &C,
+        examples: impl IntoIterator<Item = (i32, Vec<ImportExample>)>,
+    ) -> Result<(), DbErr> {
+        let models: Vec<entity::word_examples::ActiveModel> = examples
+            .into_iter()
+            .flat_map(|(word_id, examples)| {
+                examples
+                    .into_iter()
+                    .enumerate()
+                    .map(move |(idx, example)| entity::word_examples::ActiveModel {
+                        id: NotSet,
+                        word_id: Set(word_id),
+                        sentence: Set(example.sentence),
+                        translation: Set(example.translation),
+                        sort_order: Set(idx as i32),
+                    })
+            })
+            .collect();
+
+        for chunk in models.chunks(INSERT_BATCH_SIZE) {
+            entity::word_examples::Entity::insert_many(chunk.to_vec())
+                .exec_without_returning(db)
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn replace<C: ConnectionTrait>(
+        db: &C,
+        word_id: i32,
+        examples: Vec<ImportExample>,
+    ) -> Result<(), DbErr> {
+        entity::word_examples::Entity::delete_many()
+            .filter(entity::word_examples::Column::WordId.eq(word_id))
+            .exec(db)
+            .await?;
+        Self::insert(db, word_id, examples).await
+    }
+}
\ No newline at end of file
@@ -0,0 +1,3 @@
+pub mod error;
+pub mod rows;
+pub mod stream;
\ No newline at end of file
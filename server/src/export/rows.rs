@@ -0,0 +1,193 @@
+use std::mem;
+use std::sync::Arc;
+
+use futures::TryStreamExt;
+use sea_orm::ColumnTrait;
+use sea_orm::DatabaseConnection;
+use sea_orm::DbErr;
+use sea_orm::EntityTrait;
+use sea_orm::FromQueryResult;
+use sea_orm::QueryFilter;
+use sea_orm::QueryOrder;
+use sea_orm::QuerySelect;
+use sea_orm::sea_query::Expr;
+use tokio::sync::mpsc;
+
+use crate::db::DbPool;
+use crate::filter::WordFilter;
+use crate::import::data::ImportChapter;
+use crate::import::data::ImportExample;
+use crate::import::data::ImportTag;
+use crate::import::data::ImportWord;
+
+// Words per batch sent to the response body.
+const BATCH_SIZE: usize = 500;
+// Batches read ahead of a slow client before reading pauses.
+const CHANNEL_CAPACITY: usize = 4;
+
+const EXAMPLES_JSON: &str = "(SELECT json_group_array(json_object('sentence', word_examples.sentence, \
+     'translation', word_examples.translation) ORDER BY word_examples.sort_order, word_examples.id) \
+     FROM word_examples WHERE word_examples.word_id = words.id)";
+const TAGS_JSON: &str = "(SELECT json_group_array(json_object('name', tags.name, 'color', tags.color) \
+     ORDER BY tags.name) \
+     FROM word_tags JOIN tags ON tags.id = word_tags.tag_id WHERE word_tags.word_id = words.id)";
+
+#[derive(Debug, Clone, Copy)]
+pub enum ExportScope {
+    Wordbook(i32),
+    Chapter(i32),
+}
+
+// A chapter row starts a chapter; the word rows after it belong to it.
+#[derive(Debug)]
+pub enum ExportRow {
+    Chapter { order: usize, name: String },
+    Word(ImportWord),
+}
+
+#[derive(FromQueryResult)]
+struct ExportRecord {
+    chapter_id: i32,
+    chapter_name: String,
+    source: Option<String>,
+    translation: Option<String>,
+    note: Option<String>,
+    phonetic: Option<String>,
+    part_of_speech: Option<String>,
+    examples: String,
+    tags: String,
+}
+
+pub type ExportBatch = Result<Vec<ExportRow>, DbErr>;
+
+pub struct ExportRows;
+
+impl ExportRows {
+    // Rows are read on a task of their own so the response body can be sent while
+    // later batches are still being loaded.
+    pub fn spawn(
+        db: Arc<DatabaseConnection>,
+        scope: ExportScope,
+        filter: WordFilter,
+    ) -> mpsc::Receiver<ExportBatch> {
+        let (sender, receiver) = mpsc::channel(CHANNEL_CAPACITY);
+        tokio::spawn(async move {
+            if let Err(e) = Self::read(&db, scope, &filter, &sender).await {
+                let _ = sender.send(Err(e)).await;
+            }
+        });
+        receiver
+    }
+
+    // For formats that need the whole wordbook at once.
+    pub async fn load(
+        db: Arc<DatabaseConnection>,
+        scope: ExportScope,
+        filter: WordFilter,
+    ) -> Result<Vec<ImportChapter>, DbErr> {
+        let mut receiver = Self::spawn(db, scope, filter);
+        let mut chapters: Vec<ImportChapter> = Vec::new();
+        while let Some(batch) = receiver.recv().await {
+            for row in batch? {
+                match row {
+                    ExportRow::Chapter { name, .. } => {
+                        chapters.push(ImportChapter::with_words(name, Vec::new()));
+                    }
+                    ExportRow::Word(word) => {
+                        if let Some(chapter) = chapters.last_mut() {
+                            chapter.words.push(word);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(chapters)
+    }
+
+    // One query left-joins chapters to their words, so empty chapters are kept and
+    // rows come in display order; examples and tags come along as JSON arrays. It runs
+    // on a connection of its own, since it stays open while the client reads.
+    async fn read(
+        db: &DatabaseConnection,
+        scope: ExportScope,
+        filter: &WordFilter,
+        sender: &mpsc::Sender<ExportBatch>,
+    ) -> Result<(), DbErr> {
+        let db = DbPool::dedicated(db).await?;
+        let query = match scope {
+            ExportScope::Wordbook(wordbook_id) => entity::chapters::Entity::find()
+                .filter(entity::chapters::Column::WordbookId.eq(wordbook_id)),
+            ExportScope::Chapter(chapter_id) => entity::chapters::Entity::find()
+                .filter(entity::chapters::Column::Id.eq(chapter_id)),
+        };
+        let mut records = query
+            .select_only()
+            .column_as(entity::chapters::Column::Id, "chapter_id")
+            .column_as(entity::chapters::Column::Name, "chapter_name")
+            .column(entity::words::Column::Source)
+            .column(entity::words::Column::Translation)
+            .column(entity::words::Column::Note)
+            .column(entity::words::Column::Phonetic)
+            .column(entity::words::Column::PartOfSpeech)
+            .column_as(Expr::cust(EXAMPLES_JSON), "examples")
+            .column_as(Expr::cust(TAGS_JSON), "tags")
+            .left_join(entity::words::Entity)
+            .filter(filter.condition())
+            .order_by_asc(entity::chapters::Column::SortOrder)
+            .order_by_asc(entity::chapters::Column::Id)
+            .order_by_asc(entity::words::Column::SortOrder)
+            .order_by_asc(entity::words::Column::Id)
+            .into_model::<ExportRecord>()
+            .stream(&db)
+            .await?;
+
+        let mut batch = Vec::new();
+        let mut batch_words = 0;
+        let mut chapter_id = None;
+        let mut order = 0;
+        while let Some(record) = records.try_next().await? {
+            if chapter_id != Some(record.chapter_id) {
+                chapter_id = Some(record.chapter_id);
+                order += 1;
+                batch.push(ExportRow::Chapter {
+                    order,
+                    name: record.chapter_name.clone(),
+                });
+            }
+            let Some(word) = record.into_word()? else {
+                continue;
+            };
+            batch.push(ExportRow::Word(word));
+            batch_words += 1;
+            if batch_words >= BATCH_SIZE {
+                batch_words = 0;
+                // The receiver is gone once the client disconnects.
+                if sender.send(Ok(mem::take(&mut batch))).await.is_err() {
+                    return Ok(());
+                }
+            }
+        }
+        if !batch.is_empty() {
+            let _ = sender.send(Ok(batch)).await;
+        }
+        Ok(())
+    }
+}
+
+impl ExportRecord {
+    // Chapters without words come back with the word columns null.
+    fn into_word(self) -> Result<Option<ImportWord>, DbErr> {
+        let (Some(source), Some(translation)) = (self.source, self.translation) else {
+            return Ok(None);
+        };
+        let examples: Vec<ImportExample> = serde_json::from_str(&self.examples)
+            .map_err(|e| DbErr::Json(e.to_string()))?;
+        let tags: Vec<ImportTag> =
+            serde_json::from_str(&self.tags).map_err(|e| DbErr::Json(e.to_string()))?;
+        Ok(Some(
+            ImportWord::new(source, translation, self.note)
+                .with_details(self.phonetic, self.part_of_speech, examples)
+                .with_tags(tags),
+        ))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,348 @@
+use axum::body::Body;
+use futures::stream;
+use futures::StreamExt;
+use serde::Serialize;
+use tokio::sync::mpsc;
+
+use crate::export::error::ExportError;
+use crate::export::rows::ExportBatch;
+use crate::export::rows::ExportRow;
+use crate::import::data::ImportExample;
+use crate::import::data::ImportTag;
+use crate::import::data::ImportWord;
+use crate::import::data::XmlTag;
+
+pub const HEADER_CHAPTER_NAME: &str = "chapter_name";
+pub const HEADER_CHAPTER_ORDER: &str = "chapter_order";
+pub const WORD_HEADERS: [&str; 7] = [
+    "source",
+    "translation",
+    "note",
+    "phonetic",
+    "part_of_speech",
+    "examples",
+    "tags",
+];
+const XML_DECLARATION: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n";
+const JSON_INDENT: &str = "  ";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamFormat {
+    Json,
+    Xml,
+    Csv,
+}
+
+#[derive(Debug, Serialize)]
+struct XmlExportWord {
+    source: String,
+    translation: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    phonetic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part_of_speech: Option<String>,
+    #[serde(rename = "example")]
+    examples: Vec<ImportExample>,
+    #[serde(rename = "tag")]
+    tags: Vec<XmlTag>,
+}
+
+impl From<&ImportWord> for XmlExportWord {
+    fn from(word: &ImportWord) -> Self {
+        Self {
+            source: word.source.clone(),
+            translation: word.translation.clone(),
+            note: word.note.clone(),
+            phonetic: word.phonetic.clone(),
+            part_of_speech: word.part_of_speech.clone(),
+            examples: word.examples.clone(),
+            tags: word.tags.iter().map(XmlTag::from).collect(),
+        }
+    }
+}
+
+// Encodes export rows as they arrive. The output matches serializing the whole
+// wordbook or chapter at once: pretty JSON, compact XML and CSV with a header row.
+pub struct StreamWriter {
+    format: StreamFormat,
+    wordbook: bool,
+    chapters: usize,
+    chapter_words: usize,
+    chapter_name: String,
+    chapter_order: usize,
+}
+
+pub struct ExportStream;
+
+impl StreamFormat {
+    pub fn parse(format: &str) -> Option<Self> {
+        match format {
+            "json" => Some(Self::Json),
+            "xml" => Some(Self::Xml),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::Xml => "application/xml",
+            Self::Csv => "text/csv; charset=utf-8",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Xml => "xml",
+            Self::Csv => "csv",
+        }
+    }
+}
+
+impl StreamWriter {
+    pub fn wordbook(
+        format: StreamFormat,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<(Self, String), ExportError> {
+        let writer = Self::new(format, true);
+        let head = match format {
+            StreamFormat::Json => format!(
+                "{{\n{i}\"name\": {},\n{i}\"description\": {},\n{i}\"chapters\": [",
+                Self::json(&name)?,
+                Self::json(&description)?,
+                i = JSON_INDENT,
+            ),
+            StreamFormat::Xml => {
+                let mut head = format!("{}<wordbook>{}", XML_DECLARATION, Self::xml("name", &name)?);
+                if let Some(description) = description {
+                    head.push_str(&Self::xml("description", &description)?);
+                }
+                head
+            }
+            StreamFormat::Csv => format!(
+                "{},{},{}\n",
+                HEADER_CHAPTER_NAME,
+                WORD_HEADERS.join(","),
+                HEADER_CHAPTER_ORDER
+            ),
+        };
+        Ok((writer, head))
+    }
+
+    // A chapter export is a single chapter object, so its chapter row is not repeated.
+    pub fn chapter(format: StreamFormat, name: &str) -> Result<(Self, String), ExportError> {
+        let mut writer = Self::new(format, false);
+        writer.chapters = 1;
+        let head = match format {
+            StreamFormat::Json => format!(
+                "{{\n{i}\"name\": {},\n{i}\"words\": [",
+                Self::json(&name)?,
+                i = JSON_INDENT,
+            ),
+            StreamFormat::Xml => format!("{}<chapter>{}", XML_DECLARATION, Self::xml("name", &name)?),
+            StreamFormat::Csv => format!("{}\n", WORD_HEADERS.join(",")),
+        };
+        Ok((writer, head))
+    }
+
+    fn new(format: StreamFormat, wordbook: bool) -> Self {
+        Self {
+            format,
+            wordbook,
+            chapters: 0,
+            chapter_words: 0,
+            chapter_name: String::new(),
+            chapter_order: 0,
+        }
+    }
+
+    pub fn write(&mut self, rows: &[ExportRow]) -> Result<String, ExportError> {
+        let mut content = String::new();
+        for row in rows {
+            match row {
+                ExportRow::Chapter { order, name } if self.wordbook => {
+                    self.start_chapter(&mut content, *order, name)?;
+                }
+                ExportRow::Chapter { .. } => {}
+                ExportRow::Word(word) => self.write_word(&mut content, word)?,
+            }
+        }
+        Ok(content)
+    }
+
+    pub fn finish(self) -> String {
+        let mut content = String::new();
+        if self.chapters > 0 {
+            self.end_chapter(&mut content);
+        }
+        if self.wordbook {
+            match self.format {
+                StreamFormat::Json if self.chapters > 0 => {
+                    content.push_str(&format!("\n{}]\n}}", JSON_INDENT));
+                }
+                StreamFormat::Json => content.push_str("]\n}"),
+                StreamFormat::Xml => content.push_str("</wordbook>"),
+                StreamFormat::Csv => {}
+            }
+        }
+        content
+    }
+
+    fn start_chapter(
+        &mut self,
+        content: &mut String,
+        order: usize,
+        name: &str,
+    ) -> Result<(), ExportError> {
+        if self.chapters > 0 {
+            self.end_chapter(content);
+        }
+        match self.format {
+            StreamFormat::Json => {
+                let indent = JSON_INDENT.repeat(2);
+                if self.chapters > 0 {
+                    content.push(',');
+                }
+                content.push_str(&format!(
+                    "\n{indent}{{\n{indent}{i}\"name\": {},\n{indent}{i}\"words\": [",
+                    Self::json(&name)?,
+                    i = JSON_INDENT,
+                ));
+            }
+            StreamFormat::Xml => {
+                content.push_str("<chapter>");
+                content.push_str(&Self::xml("name", &name)?);
+            }
+            StreamFormat::Csv => {}
+        }
+        self.chapters += 1;
+        self.chapter_words = 0;
+        self.chapter_name = name.to_string();
+        self.chapter_order = order;
+        Ok(())
+    }
+
+    fn end_chapter(&self, content: &mut String) {
+        match self.format {
+            StreamFormat::Json => {
+                let indent = self.chapter_indent();
+                if self.chapter_words > 0 {
+                    content.push_str(&format!("\n{}{}", indent, JSON_INDENT));
+                }
+                content.push(']');
+                content.push_str(&format!("\n{}}}", indent));
+            }
+            StreamFormat::Xml => content.push_str("</chapter>"),
+            StreamFormat::Csv => {}
+        }
+    }
+
+    fn write_word(&mut self, content: &mut String, word: &ImportWord) -> Result<(), ExportError> {
+        match self.format {
+            StreamFormat::Json => {
+                let indent = format!("{}{}", self.chapter_indent(), JSON_INDENT.repeat(2));
+                let json = serde_json::to_string_pretty(word)
+                    .map_err(|e| ExportError::SerializeError(e.to_string()))?;
+                if self.chapter_words > 0 {
+                    content.push(',');
+                }
+                // Strings are escaped in JSON, so every line break is between tokens.
+                for line in json.lines() {
+                    content.push('\n');
+                    content.push_str(&indent);
+                    content.push_str(line);
+                }
+            }
+            StreamFormat::Xml => content.push_str(&Self::xml("word", &XmlExportWord::from(word))?),
+            StreamFormat::Csv if self.wordbook => {
+                content.push_str(&format!(
+                    "{},{},{}\n",
+                    Self::escape_csv(&self.chapter_name),
+                    Self::word_to_csv(word),
+                    self.chapter_order
+                ));
+            }
+            StreamFormat::Csv => {
+                content.push_str(&Self::word_to_csv(word));
+                content.push('\n');
+            }
+        }
+        self.chapter_words += 1;
+        Ok(())
+    }
+
+    fn chapter_indent(&self) -> String {
+        match self.wordbook {
+            true => JSON_INDENT.repeat(2),
+            false => String::new(),
+        }
+    }
+
+    fn json<T: Serialize>(value: &T) -> Result<String, ExportError> {
+        serde_json::to_string(value).map_err(|e| ExportError::SerializeError(e.to_string()))
+    }
+
+    fn xml<T: Serialize>(root: &str, value: &T) -> Result<String, ExportError> {
+        quick_xml::se::to_string_with_root(root, value)
+            .map_err(|e| ExportError::SerializeError(e.to_string()))
+    }
+
+    fn word_to_csv(word: &ImportWord) -> String {
+        Self::word_columns(word)
+            .iter()
+            .map(|value| Self::escape_csv(value))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    pub fn word_columns(word: &ImportWord) -> [String; 7] {
+        [
+            word.source.clone(),
+            word.translation.clone(),
+            word.note.clone().unwrap_or_default(),
+            word.phonetic.clone().unwrap_or_default(),
+            word.part_of_speech.clone().unwrap_or_default(),
+            ImportExample::join(&word.examples),
+            ImportTag::join(&word.tags),
+        ]
+    }
+
+    fn escape_csv(value: &str) -> String {
+        if value.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value.to_string()
+        }
+    }
+}
+
+impl ExportStream {
+    // The response has started by the time a later batch fails, so an error ends
+    // the body early and the client sees an incomplete download.
+    pub fn body(receiver: mpsc::Receiver<ExportBatch>, writer: StreamWriter, head: String) -> Body {
+        let rows = stream::unfold(Some((receiver, writer)), |state| async move {
+            let (mut receiver, mut writer) = state?;
+            match receiver.recv().await {
+                Some(Ok(rows)) => match writer.write(&rows) {
+                    Ok(chunk) => Some((Ok(chunk), Some((receiver, writer)))),
+                    Err(e) => Some((Err(e), None)),
+                },
+                Some(Err(e)) => Some((Err(ExportError::from(e)), None)),
+                None => Some((Ok(writer.finish()), None)),
+            }
+        });
+        let chunks = stream::once(async { Ok(head) })
+            .chain(rows)
+            .inspect(|chunk| {
+                if let Err(e) = chunk {
+                    tracing::error!("Export failed while streaming: {}", e);
+                }
+            });
+        Body::from_stream(chunks)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,163 @@
+use std::ops::Bound;
+
+use chrono::DateTime;
+use chrono::Days;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::Utc;
+use sea_orm::sea_query::LikeExpr;
+use sea_orm::sea_query::Query;
+use sea_orm::sea_query::SelectStatement;
+use sea_orm::ColumnTrait;
+use sea_orm::Condition;
+use serde::Deserialize;
+
+use crate::error::AppError;
+use crate::search::SearchTerms;
+use crate::time::ClientTime;
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+// Filters shared by word listing and export. Ids are comma-separated lists.
+#[derive(Debug, Default, Deserialize)]
+pub struct WordFilterParams {
+    pub tag_ids: Option<String>,
+    pub exclude_tag_ids: Option<String>,
+    pub created_from: Option<String>,
+    pub created_to: Option<String>,
+    pub tz_offset: Option<i32>,
+    pub q: Option<String>,
+    pub word_ids: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct WordFilter {
+    tag_ids: Vec<i32>,
+    exclude_tag_ids: Vec<i32>,
+    created_from: Option<DateTime<Utc>>,
+    created_to: Bound<DateTime<Utc>>,
+    query: Option<String>,
+    word_ids: Option<Vec<i32>>,
+}
+
+impl WordFilterParams {
+    pub fn parse(&self) -> Result<WordFilter, AppError> {
+        let offset = ClientTime::offset(self.tz_offset)?;
+
+        let created_from = match self.created_from.as_deref().map(str::trim) {
+            Some(value) if !value.is_empty() => Some(Self::parse_time(value, offset, false)?.0),
+            _ => None,
+        };
+        // A date alone covers that whole local day.
+        let created_to = match self.created_to.as_deref().map(str::trim) {
+            Some(value) if !value.is_empty() => match Self::parse_time(value, offset, true)? {
+                (time, true) => Bound::Excluded(time),
+                (time, false) => Bound::Included(time),
+            },
+            _ => Bound::Unbounded,
+        };
+
+        Ok(WordFilter {
+            tag_ids: Self::parse_ids("tag_ids", self.tag_ids.as_deref())?,
+            exclude_tag_ids: Self::parse_ids("exclude_tag_ids", self.exclude_tag_ids.as_deref())?,
+            created_from,
+            created_to,
+            query: self
+                .q
+                .as_deref()
+                .map(str::trim)
+                .filter(|q| !q.is_empty())
+                .map(String::from),
+            word_ids: match self.word_ids.as_deref() {
+                Some(value) if !value.trim().is_empty() => {
+                    Some(Self::parse_ids("word_ids", Some(value))?)
+                }
+                _ => None,
+            },
+        })
+    }
+
+    fn parse_ids(name: &str, value: Option<&str>) -> Result<Vec<i32>, AppError> {
+        value
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(|id| {
+                id.parse()
+                    .map_err(|_| AppError::Validation(format!("Invalid {}: {}", name, id)))
+            })
+            .collect()
+    }
+
+    // Accepts RFC 3339 times or YYYY-MM-DD dates in the tz_offset time zone. The flag
+    // is set for a date, whose end bound is the start of the next day.
+    fn parse_time(
+        value: &str,
+        offset: FixedOffset,
+        end: bool,
+    ) -> Result<(DateTime<Utc>, bool), AppError> {
+        if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+            return Ok((time.with_timezone(&Utc), false));
+        }
+        let invalid = || AppError::Validation(format!("Invalid date: {}", value));
+        let mut date = NaiveDate::parse_from_str(value, DATE_FORMAT).map_err(|_| invalid())?;
+        if end {
+            date = date.checked_add_days(Days::new(1)).ok_or_else(invalid)?;
+        }
+        let time = date
+            .and_hms_opt(0, 0, 0)
+            .and_then(|time| time.and_local_timezone(offset).single())
+            .ok_or_else(invalid)?;
+        Ok((time.with_timezone(&Utc), end))
+    }
+}
+
+impl WordFilter {
+    // Conditions on the words table. A left-joined row without a word never matches
+    // a non-empty filter, so chapters with no matching words drop out of exports.
+    pub fn condition(&self) -> Condition {
+        let mut condition = Condition::all();
+        if !self.tag_ids.is_empty() {
+            condition = condition
+                .add(entity::words::Column::Id.in_subquery(Self::tagged_words(&self.tag_ids)));
+        }
+        if !self.exclude_tag_ids.is_empty() {
+            condition = condition.add(
+                entity::words::Column::Id.not_in_subquery(Self::tagged_words(&self.exclude_tag_ids)),
+            );
+        }
+        if let Some(from) = self.created_from {
+            condition = condition.add(entity::words::Column::CreatedAt.gte(from.fixed_offset()));
+        }
+        match self.created_to {
+            Bound::Included(to) => {
+                condition = condition.add(entity::words::Column::CreatedAt.lte(to.fixed_offset()));
+            }
+            Bound::Excluded(to) => {
+                condition = condition.add(entity::words::Column::CreatedAt.lt(to.fixed_offset()));
+            }
+            Bound::Unbounded => {}
+        }
+        if let Some(query) = &self.query {
+            let pattern = format!("%{}%", SearchTerms::escape_like(query));
+            condition = condition.add(
+                Condition::any()
+                    .add(entity::words::Column::Source.like(LikeExpr::new(&pattern).escape('\\')))
+                    .add(entity::words::Column::Translation.like(LikeExpr::new(pattern).escape('\\'))),
+            );
+        }
+        if let Some(word_ids) = &self.word_ids {
+            condition = condition.add(entity::words::Column::Id.is_in(word_ids.clone()));
+        }
+        condition
+    }
+
+    fn tagged_words(tag_ids: &[i32]) -> SelectStatement {
+        Query::select()
+            .column(entity::word_tags::Column::WordId)
+            .from(entity::word_tags::Entity)
+            .and_where(entity::word_tags::Column::TagId.is_in(tag_ids.to_vec()))
+            .to_owned()
+    }
+}
\ No newline at end of file
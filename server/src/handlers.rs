@@ -1,7 +1,13 @@
+pub mod account_handler;
 pub mod auth_handler;
 pub mod chapter_handler;
+pub mod dictionary_handler;
 pub mod export_handler;
 pub mod import_handler;
+pub mod quiz_handler;
+pub mod review_handler;
+pub mod search_handler;
+pub mod stats_handler;
 pub mod tag_handler;
 pub mod word_handler;
 pub mod wordbook_handler;
\ No newline at end of file
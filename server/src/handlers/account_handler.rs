@@ -0,0 +1,78 @@
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::header;
+use axum::http::StatusCode;
+use axum::response::Response;
+use axum::Json;
+use axum_extra::extract::Multipart;
+use tower_sessions::Session;
+
+use crate::auth::session::UserSession;
+use crate::backup::archive::BackupArchive;
+use crate::backup::data::BACKUP_FORMAT;
+use crate::backup::store::BackupStore;
+use crate::backup::store::RestoreResult;
+use crate::error::AppError;
+use crate::state::AppState;
+
+const CONTENT_TYPE_ZIP: &str = "application/zip";
+
+pub struct AccountHandler;
+
+impl AccountHandler {
+    async fn get_user_id(session: &Session) -> Result<i32, AppError> {
+        UserSession::get(session)
+            .await?
+            .ok_or(AppError::Unauthorized)
+    }
+
+    pub async fn export(
+        State(state): State<AppState>,
+        session: Session,
+    ) -> Result<Response, AppError> {
+        let user_id = Self::get_user_id(&session).await?;
+
+        let backup = BackupStore::collect(state.db.as_ref(), user_id).await?;
+        let filename = format!(
+            "{}-{}-{}.zip",
+            BACKUP_FORMAT,
+            backup.profile.username,
+            backup.manifest.exported_at.format("%Y%m%d")
+        );
+        let content = BackupArchive::write(&backup).map_err(|e| AppError::Internal(e.to_string()))?;
+
+        Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, CONTENT_TYPE_ZIP)
+            .header(
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", filename),
+            )
+            .body(Body::from(content))
+            .map_err(|e| AppError::Internal(e.to_string()))
+    }
+
+    pub async fn import(
+        State(state): State<AppState>,
+        session: Session,
+        mut multipart: Multipart,
+    ) -> Result<Json<RestoreResult>, AppError> {
+        let user_id = Self::get_user_id(&session).await?;
+
+        let mut file_data = None;
+        while let Some(field) = multipart.next_field().await.map_err(|e| {
+            AppError::Validation(format!("Failed to read multipart field: {}", e))
+        })? {
+            if field.name() == Some("file") {
+                file_data = Some(field.bytes().await.map_err(|e| {
+                    AppError::Validation(format!("Failed to read file: {}", e))
+                })?);
+            }
+        }
+        let data = file_data.ok_or_else(|| AppError::Validation("No file uploaded".to_string()))?;
+
+        let backup = BackupArchive::read(&data).map_err(|e| AppError::Validation(e.to_string()))?;
+        let result = BackupStore::restore(state.db.as_ref(), user_id, backup).await?;
+        Ok(Json(result))
+    }
+}
\ No newline at end of file
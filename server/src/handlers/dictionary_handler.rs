@@ -0,0 +1,60 @@
+use axum::extract::Query;
+use axum::extract::State;
+use axum::Json;
+use serde::Deserialize;
+use serde::Serialize;
+use tower_sessions::Session;
+
+use crate::auth::session::UserSession;
+use crate::dictionary::lookup::DictionaryLookup;
+use crate::error::AppError;
+use crate::state::AppState;
+
+#[derive(Debug, Deserialize)]
+pub struct LookupQuery {
+    pub word: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DictionaryEntryResponse {
+    pub word: String,
+    pub phonetic: Option<String>,
+    pub translation: String,
+    pub definition: Option<String>,
+    pub source: String,
+}
+
+impl From<entity::dictionary_entries::Model> for DictionaryEntryResponse {
+    fn from(entry: entity::dictionary_entries::Model) -> Self {
+        Self {
+            word: entry.headword,
+            phonetic: entry.phonetic,
+            translation: entry.translation,
+            definition: entry.definition,
+            source: entry.source,
+        }
+    }
+}
+
+pub struct DictionaryHandler;
+
+impl DictionaryHandler {
+    pub async fn lookup(
+        State(state): State<AppState>,
+        session: Session,
+        Query(query): Query<LookupQuery>,
+    ) -> Result<Json<DictionaryEntryResponse>, AppError> {
+        UserSession::get(&session)
+            .await?
+            .ok_or(AppError::Unauthorized)?;
+
+        if query.word.trim().is_empty() {
+            return Err(AppError::Validation("word is required".to_string()));
+        }
+
+        DictionaryLookup::find(state.db.as_ref(), &query.word)
+            .await?
+            .map(|entry| Json(DictionaryEntryResponse::from(entry)))
+            .ok_or_else(|| AppError::NotFound(format!("No dictionary entry for '{}'", query.word.trim())))
+    }
+}
\ No newline at end of file
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use axum::body::Body;
 use axum::extract::Path;
 use axum::extract::Query;
@@ -6,59 +8,67 @@ use axum::http::header;
 use axum::http::StatusCode;
 use axum::response::Response;
 use rust_xlsxwriter::Workbook;
+use rust_xlsxwriter::Worksheet;
+use rust_xlsxwriter::XlsxError;
 use sea_orm::ColumnTrait;
 use sea_orm::EntityTrait;
 use sea_orm::QueryFilter;
-use sea_orm::QueryOrder;
 use serde::Deserialize;
-use serde::Serialize;
 use tower_sessions::Session;
 
 use crate::auth::session::UserSession;
 use crate::error::AppError;
+use crate::export::rows::ExportRows;
+use crate::export::rows::ExportScope;
+use crate::export::stream::ExportStream;
+use crate::export::stream::StreamFormat;
+use crate::export::stream::StreamWriter;
+use crate::export::stream::HEADER_CHAPTER_NAME;
+use crate::export::stream::HEADER_CHAPTER_ORDER;
+use crate::export::stream::WORD_HEADERS;
+use crate::filter::WordFilterParams;
+use crate::import::anki::AnkiPackage;
 use crate::import::data::ImportChapter;
 use crate::import::data::ImportWord;
 use crate::import::data::ImportWordbook;
+use crate::import::flashcard::FlashcardSeparators;
+use crate::import::flashcard::FlashcardText;
+use crate::import::markdown::MarkdownList;
+use crate::import::markdown::WordListStyle;
+use crate::import::ods::OdsCell;
+use crate::import::ods::OdsSheet;
+use crate::import::ods::OdsWriter;
+use crate::import::parser_excel::SheetLayout;
 use crate::state::AppState;
+use crate::worksheet::error::WorksheetError;
+use crate::worksheet::fonts::WorksheetFonts;
+use crate::worksheet::layout::WorksheetPdf;
+use crate::worksheet::layout::WorksheetLayout;
 
-const FORMAT_JSON: &str = "json";
-const FORMAT_XML: &str = "xml";
-const FORMAT_CSV: &str = "csv";
 const FORMAT_XLSX: &str = "xlsx";
+const FORMAT_ODS: &str = "ods";
+const FORMAT_APKG: &str = "apkg";
+const FORMAT_PDF: &str = "pdf";
+const FORMAT_MARKDOWN: &str = "md";
+const FORMAT_TEXT: &str = "txt";
+const FORMAT_FLASHCARDS: &str = "flashcards";
 const CONTENT_TYPE_XLSX: &str = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet";
-const HEADER_CHAPTER_NAME: &str = "chapter_name";
-const HEADER_SOURCE: &str = "source";
-const HEADER_TRANSLATION: &str = "translation";
-const HEADER_NOTE: &str = "note";
+const CONTENT_TYPE_ODS: &str = "application/vnd.oasis.opendocument.spreadsheet";
+const CONTENT_TYPE_APKG: &str = "application/apkg";
+const CONTENT_TYPE_PDF: &str = "application/pdf";
+const CONTENT_TYPE_MARKDOWN: &str = "text/markdown; charset=utf-8";
+const CONTENT_TYPE_TEXT: &str = "text/plain; charset=utf-8";
+const MAX_SHEET_NAME_CHARS: usize = 31;
 
 #[derive(Debug, Deserialize)]
 pub struct ExportQuery {
     pub format: String,
-}
-
-#[derive(Debug, Serialize)]
-#[serde(rename = "wordbook")]
-struct XmlExportWordbook {
-    name: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    description: Option<String>,
-    #[serde(rename = "chapter")]
-    chapters: Vec<XmlExportChapter>,
-}
-
-#[derive(Debug, Serialize)]
-struct XmlExportChapter {
-    name: String,
-    #[serde(rename = "word")]
-    words: Vec<XmlExportWord>,
-}
-
-#[derive(Debug, Serialize)]
-struct XmlExportWord {
-    source: String,
-    translation: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    note: Option<String>,
+    #[serde(default)]
+    pub sheet_layout: SheetLayout,
+    #[serde(default)]
+    pub layout: WorksheetLayout,
+    pub term_separator: Option<String>,
+    pub card_separator: Option<String>,
 }
 
 pub struct ExportHandler;
@@ -75,8 +85,10 @@ impl ExportHandler {
         session: Session,
         Path(wordbook_id): Path<i32>,
         Query(query): Query<ExportQuery>,
+        Query(filter): Query<WordFilterParams>,
     ) -> Result<Response, AppError> {
         let user_id = Self::get_user_id(&session).await?;
+        let filter = filter.parse()?;
 
         let wordbook = entity::wordbooks::Entity::find_by_id(wordbook_id)
             .filter(entity::wordbooks::Column::UserId.eq(user_id))
@@ -84,40 +96,30 @@ impl ExportHandler {
             .await?
             .ok_or_else(|| AppError::NotFound("Wordbook not found".to_string()))?;
 
-        let chapters = entity::chapters::Entity::find()
-            .filter(entity::chapters::Column::WordbookId.eq(wordbook_id))
-            .order_by_asc(entity::chapters::Column::SortOrder)
-            .all(state.db.as_ref())
-            .await?;
-
-        let mut import_chapters = Vec::with_capacity(chapters.len());
-
-        for chapter in chapters {
-            let words = entity::words::Entity::find()
-                .filter(entity::words::Column::ChapterId.eq(chapter.id))
-                .order_by_asc(entity::words::Column::SortOrder)
-                .all(state.db.as_ref())
-                .await?;
-
-            let import_words: Vec<ImportWord> = words
-                .into_iter()
-                .map(|w| ImportWord {
-                    source: w.source,
-                    translation: w.translation,
-                    note: w.note,
-                })
-                .collect();
-
-            import_chapters.push(ImportChapter::with_words(chapter.name, import_words));
+        let scope = ExportScope::Wordbook(wordbook_id);
+        if let Some(format) = StreamFormat::parse(&query.format) {
+            let (writer, head) = StreamWriter::wordbook(
+                format,
+                &wordbook.name,
+                wordbook.description.as_deref(),
+            )
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+            let rows = ExportRows::spawn(state.db.clone(), scope, filter);
+            return Self::build_stream_response(
+                ExportStream::body(rows, writer, head),
+                format,
+                &wordbook.name,
+            );
         }
 
         let export_data = ImportWordbook {
             name: wordbook.name.clone(),
             description: wordbook.description,
-            chapters: import_chapters,
+            chapters: ExportRows::load(state.db.clone(), scope, filter).await?,
         };
 
-        Self::build_wordbook_response(&export_data, &wordbook.name, &query.format)
+        Self::build_wordbook_response(&export_data, &wordbook.name, &query, &state.worksheet_fonts)
+            .await
     }
 
     pub async fn export_chapter(
@@ -125,8 +127,10 @@ impl ExportHandler {
         session: Session,
         Path((wordbook_id, chapter_id)): Path<(i32, i32)>,
         Query(query): Query<ExportQuery>,
+        Query(filter): Query<WordFilterParams>,
     ) -> Result<Response, AppError> {
         let user_id = Self::get_user_id(&session).await?;
+        let filter = filter.parse()?;
 
         entity::wordbooks::Entity::find_by_id(wordbook_id)
             .filter(entity::wordbooks::Column::UserId.eq(user_id))
@@ -140,128 +144,179 @@ impl ExportHandler {
             .await?
             .ok_or_else(|| AppError::NotFound("Chapter not found".to_string()))?;
 
-        let words = entity::words::Entity::find()
-            .filter(entity::words::Column::ChapterId.eq(chapter_id))
-            .order_by_asc(entity::words::Column::SortOrder)
-            .all(state.db.as_ref())
-            .await?;
+        let scope = ExportScope::Chapter(chapter_id);
+        if let Some(format) = StreamFormat::parse(&query.format) {
+            let (writer, head) = StreamWriter::chapter(format, &chapter.name)
+                .map_err(|e| AppError::Internal(e.to_string()))?;
+            let rows = ExportRows::spawn(state.db.clone(), scope, filter);
+            return Self::build_stream_response(
+                ExportStream::body(rows, writer, head),
+                format,
+                &chapter.name,
+            );
+        }
 
-        let import_words: Vec<ImportWord> = words
+        let words = ExportRows::load(state.db.clone(), scope, filter)
+            .await?
             .into_iter()
-            .map(|w| ImportWord {
-                source: w.source,
-                translation: w.translation,
-                note: w.note,
-            })
+            .flat_map(|chapter| chapter.words)
             .collect();
+        let export_data = ImportChapter::with_words(chapter.name.clone(), words);
 
-        let export_data = ImportChapter::with_words(chapter.name.clone(), import_words);
-
-        Self::build_chapter_response(&export_data, &chapter.name, &query.format)
+        Self::build_chapter_response(&export_data, &chapter.name, &query, &state.worksheet_fonts)
+            .await
     }
 
-    fn build_wordbook_response(
+    async fn build_wordbook_response(
         data: &ImportWordbook,
         name: &str,
-        format: &str,
+        query: &ExportQuery,
+        fonts: &WorksheetFonts,
     ) -> Result<Response, AppError> {
+        let format = query.format.as_str();
         match format {
-            FORMAT_JSON => {
-                let content = serde_json::to_string_pretty(data)
-                    .map_err(|e| AppError::Internal(e.to_string()))?;
-                Self::build_response(
-                    content.into_bytes(),
-                    "application/json",
-                    &format!("{}.json", name),
-                )
+            FORMAT_XLSX => {
+                let content = match query.sheet_layout {
+                    SheetLayout::Single => Self::wordbook_to_xlsx(data),
+                    SheetLayout::PerChapter => Self::wordbook_to_xlsx_sheets(data),
+                }
+                .map_err(Self::xlsx_error)?;
+                Self::build_response(content, CONTENT_TYPE_XLSX, &format!("{}.xlsx", name))
             }
-            FORMAT_XML => {
-                let xml_data = XmlExportWordbook {
-                    name: data.name.clone(),
-                    description: data.description.clone(),
-                    chapters: data
-                        .chapters
-                        .iter()
-                        .map(|c| XmlExportChapter {
-                            name: c.name.clone(),
-                            words: c
-                                .words
-                                .iter()
-                                .map(|w| XmlExportWord {
-                                    source: w.source.clone(),
-                                    translation: w.translation.clone(),
-                                    note: w.note.clone(),
-                                })
-                                .collect(),
-                        })
-                        .collect(),
+            FORMAT_ODS => {
+                let sheets = match query.sheet_layout {
+                    SheetLayout::Single => Self::wordbook_to_ods(data),
+                    SheetLayout::PerChapter => Self::wordbook_to_ods_sheets(data),
                 };
-                let content = Self::to_xml(&xml_data)?;
-                Self::build_response(content.into_bytes(), "application/xml", &format!("{}.xml", name))
+                let content =
+                    OdsWriter::write(&sheets).map_err(|e| AppError::Internal(e.to_string()))?;
+                Self::build_response(content, CONTENT_TYPE_ODS, &format!("{}.ods", name))
+            }
+            FORMAT_APKG => {
+                let content = AnkiPackage::write_wordbook(data)
+                    .await
+                    .map_err(|e| AppError::Internal(e.to_string()))?;
+                Self::build_response(content, CONTENT_TYPE_APKG, &format!("{}.apkg", name))
             }
-            FORMAT_CSV => {
-                let content = Self::wordbook_to_csv(data);
+            FORMAT_PDF => {
+                let (fonts, data, layout) = (fonts.clone(), data.clone(), query.layout);
+                let content =
+                    Self::render_pdf(move || WorksheetPdf::wordbook(&fonts, &data, layout)).await?;
+                Self::build_response(content, CONTENT_TYPE_PDF, &format!("{}.pdf", name))
+            }
+            FORMAT_MARKDOWN => {
+                let content = MarkdownList::write_wordbook(data, WordListStyle::Markdown);
                 Self::build_response(
                     content.into_bytes(),
-                    "text/csv; charset=utf-8",
-                    &format!("{}.csv", name),
+                    CONTENT_TYPE_MARKDOWN,
+                    &format!("{}.md", name),
                 )
             }
-            FORMAT_XLSX => {
-                let content = Self::wordbook_to_xlsx(data);
-                Self::build_response(content, CONTENT_TYPE_XLSX, &format!("{}.xlsx", name))
+            FORMAT_TEXT => {
+                let content = MarkdownList::write_wordbook(data, WordListStyle::PlainText);
+                Self::build_response(content.into_bytes(), CONTENT_TYPE_TEXT, &format!("{}.txt", name))
+            }
+            FORMAT_FLASHCARDS => {
+                let separators = Self::flashcard_separators(query)?;
+                let content = FlashcardText::write_wordbook(data, &separators);
+                Self::build_response(content.into_bytes(), CONTENT_TYPE_TEXT, &format!("{}.txt", name))
             }
             _ => Err(AppError::Validation(format!("Unsupported format: {}", format))),
         }
     }
 
-    fn build_chapter_response(
+    async fn build_chapter_response(
         data: &ImportChapter,
         name: &str,
-        format: &str,
+        query: &ExportQuery,
+        fonts: &WorksheetFonts,
     ) -> Result<Response, AppError> {
+        let format = query.format.as_str();
         match format {
-            FORMAT_JSON => {
-                let content = serde_json::to_string_pretty(data)
-                    .map_err(|e| AppError::Internal(e.to_string()))?;
-                Self::build_response(
-                    content.into_bytes(),
-                    "application/json",
-                    &format!("{}.json", name),
-                )
+            FORMAT_XLSX => {
+                let content = Self::chapter_to_xlsx(data).map_err(Self::xlsx_error)?;
+                Self::build_response(content, CONTENT_TYPE_XLSX, &format!("{}.xlsx", name))
             }
-            FORMAT_XML => {
-                let xml_data = XmlExportChapter {
-                    name: data.name.clone(),
-                    words: data
-                        .words
-                        .iter()
-                        .map(|w| XmlExportWord {
-                            source: w.source.clone(),
-                            translation: w.translation.clone(),
-                            note: w.note.clone(),
-                        })
-                        .collect(),
+            FORMAT_ODS => {
+                let sheet = OdsSheet {
+                    name: Self::sheet_name(&data.name, &mut HashSet::new()),
+                    rows: Self::ods_word_rows(&data.words),
                 };
-                let content = Self::to_xml(&xml_data)?;
-                Self::build_response(content.into_bytes(), "application/xml", &format!("{}.xml", name))
+                let content =
+                    OdsWriter::write(&[sheet]).map_err(|e| AppError::Internal(e.to_string()))?;
+                Self::build_response(content, CONTENT_TYPE_ODS, &format!("{}.ods", name))
+            }
+            FORMAT_APKG => {
+                let content = AnkiPackage::write_chapter(data)
+                    .await
+                    .map_err(|e| AppError::Internal(e.to_string()))?;
+                Self::build_response(content, CONTENT_TYPE_APKG, &format!("{}.apkg", name))
             }
-            FORMAT_CSV => {
-                let content = Self::chapter_to_csv(data);
+            FORMAT_PDF => {
+                let (fonts, data, layout) = (fonts.clone(), data.clone(), query.layout);
+                let content =
+                    Self::render_pdf(move || WorksheetPdf::chapter(&fonts, &data, layout)).await?;
+                Self::build_response(content, CONTENT_TYPE_PDF, &format!("{}.pdf", name))
+            }
+            FORMAT_MARKDOWN => {
+                let content = MarkdownList::write_chapter(data, WordListStyle::Markdown);
                 Self::build_response(
                     content.into_bytes(),
-                    "text/csv; charset=utf-8",
-                    &format!("{}.csv", name),
+                    CONTENT_TYPE_MARKDOWN,
+                    &format!("{}.md", name),
                 )
             }
-            FORMAT_XLSX => {
-                let content = Self::chapter_to_xlsx(data);
-                Self::build_response(content, CONTENT_TYPE_XLSX, &format!("{}.xlsx", name))
+            FORMAT_TEXT => {
+                let content = MarkdownList::write_chapter(data, WordListStyle::PlainText);
+                Self::build_response(content.into_bytes(), CONTENT_TYPE_TEXT, &format!("{}.txt", name))
+            }
+            FORMAT_FLASHCARDS => {
+                let separators = Self::flashcard_separators(query)?;
+                let content = FlashcardText::write_chapter(data, &separators);
+                Self::build_response(content.into_bytes(), CONTENT_TYPE_TEXT, &format!("{}.txt", name))
             }
             _ => Err(AppError::Validation(format!("Unsupported format: {}", format))),
         }
     }
 
+    // Subsetting multi-megabyte CJK fonts is CPU-bound, so it runs off the async runtime.
+    async fn render_pdf(
+        render: impl FnOnce() -> Result<Vec<u8>, WorksheetError> + Send + 'static,
+    ) -> Result<Vec<u8>, AppError> {
+        tokio::task::spawn_blocking(render)
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?
+            .map_err(|e| match e {
+                // Words the configured fonts cannot draw are the user's to change.
+                WorksheetError::MissingGlyphs(_) => AppError::Validation(e.to_string()),
+                _ => AppError::Internal(e.to_string()),
+            })
+    }
+
+    fn flashcard_separators(query: &ExportQuery) -> Result<FlashcardSeparators, AppError> {
+        FlashcardSeparators::from_labels(
+            query.term_separator.as_deref(),
+            query.card_separator.as_deref(),
+        )
+        .map_err(|e| AppError::Validation(e.to_string()))
+    }
+
+    fn build_stream_response(
+        body: Body,
+        format: StreamFormat,
+        name: &str,
+    ) -> Result<Response, AppError> {
+        Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, format.content_type())
+            .header(
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}.{}\"", name, format.extension()),
+            )
+            .body(body)
+            .map_err(|e| AppError::Internal(e.to_string()))
+    }
+
     fn build_response(content: Vec<u8>, content_type: &str, filename: &str) -> Result<Response, AppError> {
         Response::builder()
             .status(StatusCode::OK)
@@ -274,90 +329,140 @@ impl ExportHandler {
             .map_err(|e| AppError::Internal(e.to_string()))
     }
 
-    fn to_xml<T: Serialize>(data: &T) -> Result<String, AppError> {
-        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
-        let serialized = quick_xml::se::to_string(data)
-            .map_err(|e| AppError::Internal(e.to_string()))?;
-        xml.push_str(&serialized);
-        Ok(xml)
+    // Excel caps sheets at 1,048,576 rows and cells at 32,767 characters.
+    fn xlsx_error(e: XlsxError) -> AppError {
+        AppError::Internal(format!("Failed to write Excel file: {}", e))
     }
 
-    fn wordbook_to_csv(data: &ImportWordbook) -> String {
-        let mut content = String::from("chapter_name,source,translation,note\n");
-        for chapter in &data.chapters {
+    fn wordbook_to_xlsx(data: &ImportWordbook) -> Result<Vec<u8>, XlsxError> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+
+        let order_col = WORD_HEADERS.len() as u16 + 1;
+        worksheet.write_string(0, 0, HEADER_CHAPTER_NAME)?;
+        for (col, header) in WORD_HEADERS.iter().enumerate() {
+            worksheet.write_string(0, col as u16 + 1, *header)?;
+        }
+        worksheet.write_string(0, order_col, HEADER_CHAPTER_ORDER)?;
+
+        let mut row: u32 = 1;
+        for (ch_idx, chapter) in data.chapters.iter().enumerate() {
             for word in &chapter.words {
-                content.push_str(&Self::escape_csv(&chapter.name));
-                content.push(',');
-                content.push_str(&Self::escape_csv(&word.source));
-                content.push(',');
-                content.push_str(&Self::escape_csv(&word.translation));
-                content.push(',');
-                content.push_str(&Self::escape_csv(word.note.as_deref().unwrap_or("")));
-                content.push('\n');
+                worksheet.write_string(row, 0, &chapter.name)?;
+                for (col, value) in StreamWriter::word_columns(word).iter().enumerate() {
+                    worksheet.write_string(row, col as u16 + 1, value)?;
+                }
+                worksheet.write_number(row, order_col, (ch_idx + 1) as f64)?;
+                row += 1;
             }
         }
-        content
-    }
 
-    fn chapter_to_csv(data: &ImportChapter) -> String {
-        let mut content = String::from("source,translation,note\n");
-        for word in &data.words {
-            content.push_str(&Self::escape_csv(&word.source));
-            content.push(',');
-            content.push_str(&Self::escape_csv(&word.translation));
-            content.push(',');
-            content.push_str(&Self::escape_csv(word.note.as_deref().unwrap_or("")));
-            content.push('\n');
-        }
-        content
+        workbook.save_to_buffer()
     }
 
-    fn escape_csv(value: &str) -> String {
-        if value.contains(',') || value.contains('"') || value.contains('\n') {
-            format!("\"{}\"", value.replace('"', "\"\""))
-        } else {
-            value.to_string()
+    fn wordbook_to_xlsx_sheets(data: &ImportWordbook) -> Result<Vec<u8>, XlsxError> {
+        let mut workbook = Workbook::new();
+        let mut used_names = HashSet::new();
+
+        for chapter in &data.chapters {
+            let sheet_name = Self::sheet_name(&chapter.name, &mut used_names);
+            let worksheet = workbook.add_worksheet();
+            worksheet.set_name(&sheet_name)?;
+            Self::write_words_sheet(worksheet, &chapter.words)?;
         }
+
+        workbook.save_to_buffer()
     }
 
-    fn wordbook_to_xlsx(data: &ImportWordbook) -> Vec<u8> {
+    fn chapter_to_xlsx(data: &ImportChapter) -> Result<Vec<u8>, XlsxError> {
         let mut workbook = Workbook::new();
-        let worksheet = workbook.add_worksheet();
+        Self::write_words_sheet(workbook.add_worksheet(), &data.words)?;
+        workbook.save_to_buffer()
+    }
 
-        let _ = worksheet.write_string(0, 0, HEADER_CHAPTER_NAME);
-        let _ = worksheet.write_string(0, 1, HEADER_SOURCE);
-        let _ = worksheet.write_string(0, 2, HEADER_TRANSLATION);
-        let _ = worksheet.write_string(0, 3, HEADER_NOTE);
+    fn write_words_sheet(worksheet: &mut Worksheet, words: &[ImportWord]) -> Result<(), XlsxError> {
+        for (col, header) in WORD_HEADERS.iter().enumerate() {
+            worksheet.write_string(0, col as u16, *header)?;
+        }
 
-        let mut row: u32 = 1;
-        for chapter in &data.chapters {
+        for (idx, word) in words.iter().enumerate() {
+            let row = (idx + 1) as u32;
+            for (col, value) in StreamWriter::word_columns(word).iter().enumerate() {
+                worksheet.write_string(row, col as u16, value)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn wordbook_to_ods(data: &ImportWordbook) -> Vec<OdsSheet> {
+        let mut header = vec![OdsCell::Text(HEADER_CHAPTER_NAME.to_string())];
+        header.extend(WORD_HEADERS.iter().map(|h| OdsCell::Text(h.to_string())));
+        header.push(OdsCell::Text(HEADER_CHAPTER_ORDER.to_string()));
+
+        let mut rows = vec![header];
+        for (ch_idx, chapter) in data.chapters.iter().enumerate() {
             for word in &chapter.words {
-                let _ = worksheet.write_string(row, 0, &chapter.name);
-                let _ = worksheet.write_string(row, 1, &word.source);
-                let _ = worksheet.write_string(row, 2, &word.translation);
-                let _ = worksheet.write_string(row, 3, word.note.as_deref().unwrap_or(""));
-                row += 1;
+                let mut row = vec![OdsCell::Text(chapter.name.clone())];
+                row.extend(StreamWriter::word_columns(word).into_iter().map(OdsCell::Text));
+                row.push(OdsCell::Number((ch_idx + 1) as f64));
+                rows.push(row);
             }
         }
 
-        workbook.save_to_buffer().unwrap_or_default()
+        vec![OdsSheet {
+            name: Self::sheet_name(&data.name, &mut HashSet::new()),
+            rows,
+        }]
     }
 
-    fn chapter_to_xlsx(data: &ImportChapter) -> Vec<u8> {
-        let mut workbook = Workbook::new();
-        let worksheet = workbook.add_worksheet();
-
-        let _ = worksheet.write_string(0, 0, HEADER_SOURCE);
-        let _ = worksheet.write_string(0, 1, HEADER_TRANSLATION);
-        let _ = worksheet.write_string(0, 2, HEADER_NOTE);
+    fn wordbook_to_ods_sheets(data: &ImportWordbook) -> Vec<OdsSheet> {
+        if data.chapters.is_empty() {
+            return Self::wordbook_to_ods(data);
+        }
+        let mut used_names = HashSet::new();
+        data.chapters
+            .iter()
+            .map(|chapter| OdsSheet {
+                name: Self::sheet_name(&chapter.name, &mut used_names),
+                rows: Self::ods_word_rows(&chapter.words),
+            })
+            .collect()
+    }
 
-        for (idx, word) in data.words.iter().enumerate() {
-            let row = (idx + 1) as u32;
-            let _ = worksheet.write_string(row, 0, &word.source);
-            let _ = worksheet.write_string(row, 1, &word.translation);
-            let _ = worksheet.write_string(row, 2, word.note.as_deref().unwrap_or(""));
+    fn ods_word_rows(words: &[ImportWord]) -> Vec<Vec<OdsCell>> {
+        let mut rows = vec![WORD_HEADERS.iter().map(|h| OdsCell::Text(h.to_string())).collect()];
+        for word in words {
+            rows.push(StreamWriter::word_columns(word).into_iter().map(OdsCell::Text).collect());
         }
+        rows
+    }
 
-        workbook.save_to_buffer().unwrap_or_default()
+    // Excel sheet names are at most 31 characters, unique ignoring case, and cannot
+    // contain []:*?/\ or start or end with an apostrophe.
+    fn sheet_name(chapter_name: &str, used_names: &mut HashSet<String>) -> String {
+        let cleaned: String = chapter_name
+            .chars()
+            .map(|c| if "[]:*?/\\".contains(c) { '_' } else { c })
+            .collect();
+        let cleaned = cleaned.trim().trim_matches('\'');
+        let base = if cleaned.is_empty() { "Sheet" } else { cleaned };
+
+        let mut suffix = 1;
+        loop {
+            let tail = if suffix == 1 {
+                String::new()
+            } else {
+                format!(" ({})", suffix)
+            };
+            let head: String = base
+                .chars()
+                .take(MAX_SHEET_NAME_CHARS - tail.chars().count())
+                .collect();
+            let name = format!("{}{}", head.trim_end().trim_end_matches('\''), tail);
+            if used_names.insert(name.to_lowercase()) {
+                return name;
+            }
+            suffix += 1;
+        }
     }
 }
\ No newline at end of file
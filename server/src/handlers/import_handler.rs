@@ -1,45 +1,81 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
 use axum::body::Body;
 use axum::extract::Path;
 use axum::extract::Query;
 use axum::extract::State;
 use axum::http::header;
 use axum::http::StatusCode;
+use axum::response::sse::Event;
+use axum::response::sse::KeepAlive;
+use axum::response::sse::Sse;
 use axum::response::Response;
 use axum::Json;
 use axum_extra::extract::Multipart;
-use chrono::Utc;
-use sea_orm::ActiveModelTrait;
-use sea_orm::ActiveValue::NotSet;
+use encoding_rs::Encoding;
+use futures::stream;
+use futures::Stream;
 use sea_orm::ColumnTrait;
+use sea_orm::DatabaseConnection;
 use sea_orm::EntityTrait;
 use sea_orm::QueryFilter;
 use sea_orm::QueryOrder;
-use sea_orm::Set;
+use sea_orm::QuerySelect;
 use serde::Deserialize;
 use serde::Serialize;
 use tower_sessions::Session;
 
 use crate::auth::session::UserSession;
+use crate::dictionary::ecdict::EcdictParser;
+use crate::dictionary::entry::DictionaryEntry;
+use crate::dictionary::lookup::DictionaryLookup;
 use crate::error::AppError;
+use crate::import::anki::AnkiPackage;
+use crate::import::columns::ColumnMap;
 use crate::import::data::ImportChapter;
 use crate::import::data::ImportWordbook;
+use crate::import::encoding::TextDecoder;
 use crate::import::error::ImportError;
+use crate::import::flashcard::FlashcardSeparators;
+use crate::import::flashcard::FlashcardText;
+use crate::import::jobs::ImportJobView;
+use crate::import::jobs::ImportJobs;
+use crate::import::jobs::JobError;
+use crate::import::kindle::KindleVocab;
+use crate::import::markdown::MarkdownList;
+use crate::import::markdown::WordListStyle;
+use crate::import::parser_csv::CsvParser;
 use crate::import::parser_excel::ExcelParser;
+use crate::import::parser_excel::SheetLayout;
 use crate::import::parser_json::JsonParser;
 use crate::import::parser_xml::XmlParser;
 use crate::import::template::TemplateGenerator;
+use crate::import::validate::ImportReport;
+use crate::import::validate::ImportValidator;
+use crate::import::writer::DuplicateStrategy;
+use crate::import::writer::ImportResult;
+use crate::import::writer::ImportTarget;
+use crate::import::writer::ImportWriter;
 use crate::state::AppState;
 
 const FORMAT_JSON: &str = "json";
 const FORMAT_XML: &str = "xml";
 const FORMAT_XLSX: &str = "xlsx";
 const FORMAT_CSV: &str = "csv";
+const FORMAT_APKG: &str = "apkg";
+const FORMAT_KINDLE: &str = "db";
+const FORMAT_MARKDOWN: &str = "md";
+const FORMAT_TEXT: &str = "txt";
 const TARGET_WORDBOOK: &str = "wordbook";
 const TARGET_CHAPTER: &str = "chapter";
 const CONTENT_TYPE_JSON: &str = "application/json";
 const CONTENT_TYPE_XML: &str = "application/xml";
 const CONTENT_TYPE_XLSX: &str = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet";
 const CONTENT_TYPE_CSV: &str = "text/csv; charset=utf-8";
+const CONTENT_TYPE_MARKDOWN: &str = "text/markdown; charset=utf-8";
+const JOB_HISTORY_LIMIT: u64 = 50;
+const JOB_EVENT: &str = "progress";
 
 #[derive(Debug, Deserialize)]
 pub struct TemplateQuery {
@@ -47,10 +83,49 @@ pub struct TemplateQuery {
     pub target: String,
 }
 
+#[derive(Debug, Default)]
+struct ImportForm {
+    file_data: Option<Vec<u8>>,
+    file_name: Option<String>,
+    name: Option<String>,
+    preview: bool,
+    // Imports run as background jobs unless `async=false` is sent.
+    run_async: Option<bool>,
+    on_duplicate: DuplicateStrategy,
+    target_chapter_id: Option<i32>,
+    term_separator: Option<String>,
+    card_separator: Option<String>,
+    options: ImportOptions,
+}
+
+#[derive(Debug, Default)]
+struct ImportOptions {
+    column_map: ColumnMap,
+    sheet_layout: SheetLayout,
+    encoding: Option<&'static Encoding>,
+    autofill: bool,
+    dictionary: Option<Vec<u8>>,
+    flashcard: Option<FlashcardSeparators>,
+}
+
 #[derive(Debug, Serialize)]
-pub struct ImportResult {
-    pub chapters_created: usize,
-    pub words_created: usize,
+pub struct ImportPreview {
+    pub preview: bool,
+    pub chapters_to_create: usize,
+    pub words_to_create: usize,
+    pub words_to_update: usize,
+    pub words_to_skip: usize,
+    pub chapters: Vec<ImportChapter>,
+    #[serde(flatten)]
+    pub report: ImportReport,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum ImportResponse {
+    Imported(ImportResult),
+    Preview(ImportPreview),
+    Queued(ImportJobView),
 }
 
 pub struct ImportHandler;
@@ -105,6 +180,16 @@ impl ImportHandler {
                 CONTENT_TYPE_CSV,
                 "chapter_template.csv",
             ),
+            (FORMAT_MARKDOWN, TARGET_WORDBOOK) => (
+                TemplateGenerator::markdown_wordbook_template().into_bytes(),
+                CONTENT_TYPE_MARKDOWN,
+                "wordbook_template.md",
+            ),
+            (FORMAT_MARKDOWN, TARGET_CHAPTER) => (
+                TemplateGenerator::markdown_chapter_template().into_bytes(),
+                CONTENT_TYPE_MARKDOWN,
+                "chapter_template.md",
+            ),
             _ => {
                 return Err(AppError::Validation(
                     "Invalid format or target".to_string(),
@@ -126,51 +211,74 @@ impl ImportHandler {
     pub async fn import_wordbook(
         State(state): State<AppState>,
         session: Session,
-        mut multipart: Multipart,
-    ) -> Result<Json<ImportResult>, AppError> {
+        multipart: Multipart,
+    ) -> Result<Json<ImportResponse>, AppError> {
         let user_id = Self::get_user_id(&session).await?;
 
-        let mut file_data: Option<Vec<u8>> = None;
-        let mut file_name: Option<String> = None;
-        let mut wordbook_name: Option<String> = None;
-
-        while let Some(field) = multipart.next_field().await.map_err(|e| {
-            AppError::Validation(format!("Failed to read multipart field: {}", e))
-        })? {
-            let name = field.name().unwrap_or_default().to_string();
-            match name.as_str() {
-                "file" => {
-                    file_name = field.file_name().map(String::from);
-                    file_data = Some(field.bytes().await.map_err(|e| {
-                        AppError::Validation(format!("Failed to read file: {}", e))
-                    })?.to_vec());
-                }
-                "name" => {
-                    wordbook_name = Some(
-                        field
-                            .text()
-                            .await
-                            .map_err(|e| AppError::Validation(format!("Failed to read name: {}", e)))?,
-                    );
-                }
-                _ => {}
-            }
+        let form = Self::read_form(multipart).await?;
+        let data = form
+            .file_data
+            .ok_or_else(|| AppError::Validation("No file uploaded".to_string()))?;
+        let name = form.file_name.unwrap_or_else(|| "unknown".to_string());
+        let wb_name = form.name.unwrap_or_else(|| "Imported Wordbook".to_string());
+
+        if form.run_async.unwrap_or(true) && !form.preview {
+            let job =
+                ImportJobs::create(state.db.as_ref(), user_id, TARGET_WORDBOOK, &name, None).await?;
+            let db = state.db.clone();
+            state.import_jobs.spawn(state.db.clone(), &job, move |reporter| async move {
+                let (wordbook, report) = Self::prepare_wordbook(
+                    &db,
+                    &data,
+                    &name,
+                    wb_name,
+                    &form.options,
+                )
+                .await?;
+                reporter.start(wordbook.chapters.iter().map(|c| c.words.len()).sum());
+                Self::check_job_report(&report)?;
+                let progress = |rows| reporter.advance(rows);
+                Ok(ImportWriter::save_wordbook(&db, user_id, wordbook, form.on_duplicate, &progress)
+                    .await?)
+            });
+            return Ok(Json(ImportResponse::Queued(job)));
         }
 
-        let data = file_data.ok_or_else(|| AppError::Validation("No file uploaded".to_string()))?;
-        let name = file_name.unwrap_or_else(|| "unknown".to_string());
-        let wb_name = wordbook_name.unwrap_or_else(|| "Imported Wordbook".to_string());
-
-        let wordbook = Self::parse_wordbook(&data, &name, wb_name)?;
-        Self::save_wordbook(&state, user_id, wordbook).await
+        let (wordbook, report) = Self::prepare_wordbook(
+            &state.db,
+            &data,
+            &name,
+            wb_name,
+            &form.options,
+        )
+        .await?;
+        if form.preview {
+            let planned = ImportWriter::preview_wordbook(&wordbook, form.on_duplicate);
+            return Ok(Json(ImportResponse::Preview(Self::build_preview(
+                wordbook.chapters,
+                report,
+                planned,
+            ))));
+        }
+        Self::check_report(&report)?;
+
+        let result = ImportWriter::save_wordbook(
+            state.db.as_ref(),
+            user_id,
+            wordbook,
+            form.on_duplicate,
+            &|_| {},
+        )
+        .await?;
+        Ok(Json(ImportResponse::Imported(result)))
     }
 
     pub async fn import_chapter(
         State(state): State<AppState>,
         session: Session,
         Path(wordbook_id): Path<i32>,
-        mut multipart: Multipart,
-    ) -> Result<Json<ImportResult>, AppError> {
+        multipart: Multipart,
+    ) -> Result<Json<ImportResponse>, AppError> {
         let user_id = Self::get_user_id(&session).await?;
 
         entity::wordbooks::Entity::find_by_id(wordbook_id)
@@ -179,45 +287,357 @@ impl ImportHandler {
             .await?
             .ok_or_else(|| AppError::NotFound("Wordbook not found".to_string()))?;
 
-        let mut file_data: Option<Vec<u8>> = None;
-        let mut file_name: Option<String> = None;
-        let mut chapter_name: Option<String> = None;
+        let form = Self::read_form(multipart).await?;
+        if let Some(chapter_id) = form.target_chapter_id {
+            entity::chapters::Entity::find_by_id(chapter_id)
+                .filter(entity::chapters::Column::WordbookId.eq(wordbook_id))
+                .one(state.db.as_ref())
+                .await?
+                .ok_or_else(|| AppError::NotFound("Chapter not found".to_string()))?;
+        }
+
+        let data = form
+            .file_data
+            .ok_or_else(|| AppError::Validation("No file uploaded".to_string()))?;
+        let name = form.file_name.unwrap_or_else(|| "unknown".to_string());
+        let ch_name = form.name.unwrap_or_else(|| "Imported Chapter".to_string());
+        let target = ImportTarget {
+            wordbook_id,
+            chapter_id: form.target_chapter_id,
+        };
+
+        if form.run_async.unwrap_or(true) && !form.preview {
+            let job = ImportJobs::create(
+                state.db.as_ref(),
+                user_id,
+                TARGET_CHAPTER,
+                &name,
+                Some(wordbook_id),
+            )
+            .await?;
+            let db = state.db.clone();
+            state.import_jobs.spawn(state.db.clone(), &job, move |reporter| async move {
+                let (chapter, report) = Self::prepare_chapter(
+                    &db,
+                    &data,
+                    &name,
+                    ch_name,
+                    &form.options,
+                )
+                .await?;
+                reporter.start(chapter.words.len());
+                Self::check_job_report(&report)?;
+                let progress = |rows| reporter.advance(rows);
+                Ok(
+                    ImportWriter::save_chapter(&db, user_id, target, chapter, form.on_duplicate, &progress)
+                        .await?,
+                )
+            });
+            return Ok(Json(ImportResponse::Queued(job)));
+        }
+
+        let (chapter, report) = Self::prepare_chapter(
+            &state.db,
+            &data,
+            &name,
+            ch_name,
+            &form.options,
+        )
+        .await?;
+        if form.preview {
+            let planned =
+                ImportWriter::preview_chapter(state.db.as_ref(), target, &chapter, form.on_duplicate)
+                    .await?;
+            return Ok(Json(ImportResponse::Preview(Self::build_preview(
+                vec![chapter],
+                report,
+                planned,
+            ))));
+        }
+        Self::check_report(&report)?;
+
+        let result = ImportWriter::save_chapter(
+            state.db.as_ref(),
+            user_id,
+            target,
+            chapter,
+            form.on_duplicate,
+            &|_| {},
+        )
+        .await?;
+        Ok(Json(ImportResponse::Imported(result)))
+    }
+
+    pub async fn list_jobs(
+        State(state): State<AppState>,
+        session: Session,
+    ) -> Result<Json<Vec<ImportJobView>>, AppError> {
+        let user_id = Self::get_user_id(&session).await?;
+
+        let jobs = entity::import_jobs::Entity::find()
+            .filter(entity::import_jobs::Column::UserId.eq(user_id))
+            .order_by_desc(entity::import_jobs::Column::CreatedAt)
+            .order_by_desc(entity::import_jobs::Column::Id)
+            .limit(JOB_HISTORY_LIMIT)
+            .all(state.db.as_ref())
+            .await?;
+
+        Ok(Json(
+            jobs.into_iter()
+                .map(|job| state.import_jobs.current(job))
+                .collect(),
+        ))
+    }
+
+    pub async fn get_job(
+        State(state): State<AppState>,
+        session: Session,
+        Path(job_id): Path<i32>,
+    ) -> Result<Json<ImportJobView>, AppError> {
+        let user_id = Self::get_user_id(&session).await?;
+        let job = Self::find_job(&state, user_id, job_id).await?;
+        Ok(Json(state.import_jobs.current(job)))
+    }
+
+    pub async fn job_events(
+        State(state): State<AppState>,
+        session: Session,
+        Path(job_id): Path<i32>,
+    ) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, AppError> {
+        let user_id = Self::get_user_id(&session).await?;
+
+        // Subscribe before loading the record: a finished job leaves the registry only
+        // after its record is saved, so a missing channel means the record is final.
+        let receiver = state.import_jobs.subscribe(job_id);
+        let job = Self::find_job(&state, user_id, job_id).await?;
+        let initial = match &receiver {
+            Some(receiver) => receiver.borrow().clone(),
+            None => ImportJobView::from(job),
+        };
+
+        let events = stream::unfold((Some(initial), receiver), |(next, receiver)| async move {
+            let (view, mut receiver) = match next {
+                Some(view) => (view, receiver),
+                None => {
+                    let mut receiver = receiver?;
+                    receiver.changed().await.ok()?;
+                    let view = receiver.borrow_and_update().clone();
+                    (view, Some(receiver))
+                }
+            };
+            if view.is_finished() {
+                receiver = None;
+            }
+            let event = Event::default().event(JOB_EVENT).json_data(&view);
+            Some((event, (None, receiver)))
+        });
+
+        Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+    }
+
+    async fn find_job(
+        state: &AppState,
+        user_id: i32,
+        job_id: i32,
+    ) -> Result<entity::import_jobs::Model, AppError> {
+        entity::import_jobs::Entity::find_by_id(job_id)
+            .filter(entity::import_jobs::Column::UserId.eq(user_id))
+            .one(state.db.as_ref())
+            .await?
+            .ok_or_else(|| AppError::NotFound("Import job not found".to_string()))
+    }
+
+    async fn prepare_wordbook(
+        db: &DatabaseConnection,
+        data: &[u8],
+        filename: &str,
+        wordbook_name: String,
+        options: &ImportOptions,
+    ) -> Result<(ImportWordbook, ImportReport), AppError> {
+        let mut wordbook = Self::parse_wordbook(data, filename, wordbook_name, options).await?;
+        let dictionary = Self::read_dictionary(options)?;
+        let mut skipped = ImportReport::default();
+        for chapter in &mut wordbook.chapters {
+            Self::autofill_words(db, chapter, options, &dictionary).await?;
+            if Self::is_kindle(filename, options) {
+                skipped.merge(ImportValidator::skip_untranslated(chapter));
+            }
+        }
+        let mut report = ImportValidator::validate_wordbook(&wordbook);
+        report.merge(skipped);
+        Ok((wordbook, report))
+    }
+
+    async fn prepare_chapter(
+        db: &DatabaseConnection,
+        data: &[u8],
+        filename: &str,
+        chapter_name: String,
+        options: &ImportOptions,
+    ) -> Result<(ImportChapter, ImportReport), AppError> {
+        let mut chapter = Self::parse_chapter(data, filename, chapter_name, options).await?;
+        let dictionary = Self::read_dictionary(options)?;
+        Self::autofill_words(db, &mut chapter, options, &dictionary).await?;
+        let skipped = match Self::is_kindle(filename, options) {
+            true => ImportValidator::skip_untranslated(&mut chapter),
+            false => ImportReport::default(),
+        };
+        let mut report = ImportValidator::validate_chapter(&chapter);
+        report.merge(skipped);
+        Ok((chapter, report))
+    }
+
+    // Kindle lookups have no translations of their own.
+    fn is_kindle(filename: &str, options: &ImportOptions) -> bool {
+        options.flashcard.is_none()
+            && filename
+                .rsplit('.')
+                .next()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case(FORMAT_KINDLE))
+    }
+
+    async fn read_form(mut multipart: Multipart) -> Result<ImportForm, AppError> {
+        let mut form = ImportForm::default();
 
         while let Some(field) = multipart.next_field().await.map_err(|e| {
             AppError::Validation(format!("Failed to read multipart field: {}", e))
         })? {
             let name = field.name().unwrap_or_default().to_string();
+            if name == "file" {
+                form.file_name = field.file_name().map(String::from);
+                form.file_data = Some(field.bytes().await.map_err(|e| {
+                    AppError::Validation(format!("Failed to read file: {}", e))
+                })?.to_vec());
+                continue;
+            }
+            if name == "dictionary" {
+                form.options.dictionary = Some(field.bytes().await.map_err(|e| {
+                    AppError::Validation(format!("Failed to read dictionary: {}", e))
+                })?.to_vec());
+                continue;
+            }
+
+            let value = field
+                .text()
+                .await
+                .map_err(|e| AppError::Validation(format!("Failed to read {}: {}", name, e)))?;
             match name.as_str() {
-                "file" => {
-                    file_name = field.file_name().map(String::from);
-                    file_data = Some(field.bytes().await.map_err(|e| {
-                        AppError::Validation(format!("Failed to read file: {}", e))
-                    })?.to_vec());
+                "name" => form.name = Some(value),
+                "autofill" => form.options.autofill = Self::parse_flag(&value),
+                "preview" => form.preview = Self::parse_flag(&value),
+                "async" => form.run_async = Some(Self::parse_flag(&value)),
+                "on_duplicate" => {
+                    form.on_duplicate = DuplicateStrategy::parse(&value).ok_or_else(|| {
+                        AppError::Validation(format!("Invalid on_duplicate value: {}", value))
+                    })?;
+                }
+                "target_chapter_id" if !value.trim().is_empty() => {
+                    form.target_chapter_id = Some(value.trim().parse().map_err(|_| {
+                        AppError::Validation(format!("Invalid target_chapter_id: {}", value))
+                    })?);
+                }
+                "column_map" => {
+                    form.options.column_map =
+                        ColumnMap::parse(&value).map_err(|e| AppError::Validation(e.to_string()))?;
                 }
-                "name" => {
-                    chapter_name = Some(
-                        field
-                            .text()
-                            .await
-                            .map_err(|e| AppError::Validation(format!("Failed to read name: {}", e)))?,
-                    );
+                "sheet_layout" => {
+                    form.options.sheet_layout = SheetLayout::parse(&value).ok_or_else(|| {
+                        AppError::Validation(format!("Invalid sheet_layout value: {}", value))
+                    })?;
                 }
+                "encoding" => {
+                    form.options.encoding = TextDecoder::for_label(&value)
+                        .map_err(|e| AppError::Validation(e.to_string()))?;
+                }
+                "term_separator" if !value.is_empty() => form.term_separator = Some(value),
+                "card_separator" if !value.is_empty() => form.card_separator = Some(value),
                 _ => {}
             }
         }
 
-        let data = file_data.ok_or_else(|| AppError::Validation("No file uploaded".to_string()))?;
-        let name = file_name.unwrap_or_else(|| "unknown".to_string());
-        let ch_name = chapter_name.unwrap_or_else(|| "Imported Chapter".to_string());
+        // Either separator switches the file to flashcard text, whatever its extension.
+        if form.term_separator.is_some() || form.card_separator.is_some() {
+            let separators = FlashcardSeparators::from_labels(
+                form.term_separator.as_deref(),
+                form.card_separator.as_deref(),
+            )
+            .map_err(|e| AppError::Validation(e.to_string()))?;
+            form.options.flashcard = Some(separators);
+        }
+
+        Ok(form)
+    }
+
+    fn parse_flag(value: &str) -> bool {
+        matches!(value.trim().to_lowercase().as_str(), "true" | "1" | "on" | "yes")
+    }
+
+    // An uploaded dictionary is applied first; autofill covers what it leaves empty.
+    async fn autofill_words(
+        db: &DatabaseConnection,
+        chapter: &mut ImportChapter,
+        options: &ImportOptions,
+        dictionary: &HashMap<String, DictionaryEntry>,
+    ) -> Result<(), AppError> {
+        DictionaryLookup::fill_words_from(dictionary, &mut chapter.words);
+        if options.autofill {
+            DictionaryLookup::fill_words(db, &mut chapter.words).await?;
+        }
+        Ok(())
+    }
+
+    fn read_dictionary(
+        options: &ImportOptions,
+    ) -> Result<HashMap<String, DictionaryEntry>, AppError> {
+        let Some(data) = &options.dictionary else {
+            return Ok(HashMap::new());
+        };
+        TextDecoder::decode(data, None)
+            .and_then(|content| EcdictParser::parse(content.as_bytes()))
+            .map(|entries| entries.into_iter().map(|e| (e.headword.clone(), e)).collect())
+            .map_err(|e| AppError::Validation(format!("Invalid dictionary file: {}", e)))
+    }
+
+    fn build_preview(
+        chapters: Vec<ImportChapter>,
+        report: ImportReport,
+        planned: ImportResult,
+    ) -> ImportPreview {
+        ImportPreview {
+            preview: true,
+            chapters_to_create: planned.chapters_created,
+            words_to_create: planned.words_created,
+            words_to_update: planned.words_updated,
+            words_to_skip: planned.words_skipped,
+            chapters,
+            report,
+        }
+    }
+
+    fn check_report(report: &ImportReport) -> Result<(), AppError> {
+        match report.error_summary() {
+            Some(summary) => Err(AppError::Validation(summary)),
+            None => Ok(()),
+        }
+    }
 
-        let chapter = Self::parse_chapter(&data, &name, ch_name)?;
-        Self::save_chapter(&state, wordbook_id, chapter).await
+    fn check_job_report(report: &ImportReport) -> Result<(), JobError> {
+        if report.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(JobError(report.errors.iter().map(ToString::to_string).collect()))
+        }
     }
 
-    fn parse_wordbook(
+    fn decode<'a>(data: &'a [u8], options: &ImportOptions) -> Result<Cow<'a, str>, ImportError> {
+        TextDecoder::decode(data, options.encoding)
+    }
+
+    async fn parse_wordbook(
         data: &[u8],
         filename: &str,
         wordbook_name: String,
+        options: &ImportOptions,
     ) -> Result<ImportWordbook, AppError> {
         let ext = filename
             .rsplit('.')
@@ -225,11 +645,34 @@ impl ImportHandler {
             .unwrap_or("")
             .to_lowercase();
 
+        if let Some(separators) = &options.flashcard {
+            return Self::decode(data, options)
+                .and_then(|c| FlashcardText::parse_wordbook(&c, wordbook_name, separators))
+                .map_err(|e| AppError::Validation(e.to_string()));
+        }
+
         let result = match ext.as_str() {
-            FORMAT_JSON => JsonParser::parse_wordbook(data),
-            FORMAT_XML => XmlParser::parse_wordbook(data),
-            "xlsx" | "xls" => ExcelParser::parse_wordbook(data, wordbook_name),
-            "tsv" | "csv" => Self::parse_tsv_wordbook(data, wordbook_name),
+            FORMAT_JSON => Self::decode(data, options).and_then(|c| JsonParser::parse_wordbook(&c)),
+            FORMAT_XML => Self::decode(data, options).and_then(|c| XmlParser::parse_wordbook(&c)),
+            "xlsx" | "xls" | "xlsb" | "ods" => {
+                ExcelParser::parse_wordbook(
+                    data,
+                    wordbook_name,
+                    &options.column_map,
+                    options.sheet_layout,
+                )
+            }
+            "tsv" | "csv" => Self::decode(data, options).and_then(|c| {
+                CsvParser::parse_wordbook(&c, wordbook_name, &options.column_map)
+            }),
+            FORMAT_APKG => AnkiPackage::parse_wordbook(data, wordbook_name).await,
+            FORMAT_KINDLE => KindleVocab::parse_wordbook(data, wordbook_name).await,
+            FORMAT_MARKDOWN | "markdown" => Self::decode(data, options).and_then(|c| {
+                MarkdownList::parse_wordbook(&c, wordbook_name, WordListStyle::Markdown)
+            }),
+            FORMAT_TEXT => Self::decode(data, options).and_then(|c| {
+                MarkdownList::parse_wordbook(&c, wordbook_name, WordListStyle::PlainText)
+            }),
             _ => Err(ImportError::InvalidFormat(format!(
                 "Unsupported file format: {}",
                 ext
@@ -239,10 +682,11 @@ impl ImportHandler {
         result.map_err(|e| AppError::Validation(e.to_string()))
     }
 
-    fn parse_chapter(
+    async fn parse_chapter(
         data: &[u8],
         filename: &str,
         chapter_name: String,
+        options: &ImportOptions,
     ) -> Result<ImportChapter, AppError> {
         let ext = filename
             .rsplit('.')
@@ -250,11 +694,29 @@ impl ImportHandler {
             .unwrap_or("")
             .to_lowercase();
 
+        if let Some(separators) = &options.flashcard {
+            return Self::decode(data, options)
+                .and_then(|c| FlashcardText::parse_chapter(&c, chapter_name, separators))
+                .map_err(|e| AppError::Validation(e.to_string()));
+        }
+
         let result = match ext.as_str() {
-            FORMAT_JSON => JsonParser::parse_chapter(data),
-            FORMAT_XML => XmlParser::parse_chapter(data),
-            "xlsx" | "xls" => ExcelParser::parse_chapter(data, chapter_name),
-            "tsv" | "csv" => Self::parse_tsv_chapter(data, chapter_name),
+            FORMAT_JSON => Self::decode(data, options).and_then(|c| JsonParser::parse_chapter(&c)),
+            FORMAT_XML => Self::decode(data, options).and_then(|c| XmlParser::parse_chapter(&c)),
+            "xlsx" | "xls" | "xlsb" | "ods" => {
+                ExcelParser::parse_chapter(data, chapter_name, &options.column_map)
+            }
+            "tsv" | "csv" => Self::decode(data, options).and_then(|c| {
+                CsvParser::parse_chapter(&c, chapter_name, &options.column_map)
+            }),
+            FORMAT_APKG => AnkiPackage::parse_chapter(data, chapter_name).await,
+            FORMAT_KINDLE => KindleVocab::parse_chapter(data, chapter_name).await,
+            FORMAT_MARKDOWN | "markdown" => Self::decode(data, options).and_then(|c| {
+                MarkdownList::parse_chapter(&c, chapter_name, WordListStyle::Markdown)
+            }),
+            FORMAT_TEXT => Self::decode(data, options).and_then(|c| {
+                MarkdownList::parse_chapter(&c, chapter_name, WordListStyle::PlainText)
+            }),
             _ => Err(ImportError::InvalidFormat(format!(
                 "Unsupported file format: {}",
                 ext
@@ -263,197 +725,4 @@ impl ImportHandler {
 
         result.map_err(|e| AppError::Validation(e.to_string()))
     }
-
-    fn parse_tsv_wordbook(data: &[u8], wordbook_name: String) -> Result<ImportWordbook, ImportError> {
-        let content = std::str::from_utf8(data)
-            .map_err(|e| ImportError::ParseError(e.to_string()))?;
-
-        let mut lines = content.lines();
-        let _header = lines.next().ok_or_else(|| {
-            ImportError::InvalidFormat("Empty file".to_string())
-        })?;
-
-        let mut chapters_map: std::collections::HashMap<String, Vec<crate::import::data::ImportWord>> =
-            std::collections::HashMap::new();
-
-        for (idx, line) in lines.enumerate() {
-            let parts: Vec<&str> = line.split('\t').collect();
-            if parts.len() < 3 {
-                continue;
-            }
-
-            let chapter_name = parts[0].trim().to_string();
-            let source = parts[1].trim().to_string();
-            let translation = parts[2].trim().to_string();
-            let note = parts.get(3).map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
-
-            if source.is_empty() || translation.is_empty() {
-                return Err(ImportError::invalid_data(idx + 2, "Missing source or translation"));
-            }
-
-            chapters_map
-                .entry(chapter_name)
-                .or_default()
-                .push(crate::import::data::ImportWord::new(source, translation, note));
-        }
-
-        let chapters = chapters_map
-            .into_iter()
-            .map(|(name, words)| ImportChapter::with_words(name, words))
-            .collect();
-
-        Ok(ImportWordbook {
-            name: wordbook_name,
-            description: None,
-            chapters,
-        })
-    }
-
-    fn parse_tsv_chapter(data: &[u8], chapter_name: String) -> Result<ImportChapter, ImportError> {
-        let content = std::str::from_utf8(data)
-            .map_err(|e| ImportError::ParseError(e.to_string()))?;
-
-        let mut lines = content.lines();
-        let _header = lines.next().ok_or_else(|| {
-            ImportError::InvalidFormat("Empty file".to_string())
-        })?;
-
-        let mut words = Vec::new();
-
-        for (idx, line) in lines.enumerate() {
-            let parts: Vec<&str> = line.split('\t').collect();
-            if parts.len() < 2 {
-                continue;
-            }
-
-            let source = parts[0].trim().to_string();
-            let translation = parts[1].trim().to_string();
-            let note = parts.get(2).map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
-
-            if source.is_empty() || translation.is_empty() {
-                return Err(ImportError::invalid_data(idx + 2, "Missing source or translation"));
-            }
-
-            words.push(crate::import::data::ImportWord::new(source, translation, note));
-        }
-
-        Ok(ImportChapter::with_words(chapter_name, words))
-    }
-
-    async fn save_wordbook(
-        state: &AppState,
-        user_id: i32,
-        wordbook: ImportWordbook,
-    ) -> Result<Json<ImportResult>, AppError> {
-        let now = Utc::now().fixed_offset();
-
-        let max_order = entity::wordbooks::Entity::find()
-            .filter(entity::wordbooks::Column::UserId.eq(user_id))
-            .order_by_desc(entity::wordbooks::Column::SortOrder)
-            .one(state.db.as_ref())
-            .await?
-            .map(|w| w.sort_order + 1)
-            .unwrap_or(0);
-
-        let wb = entity::wordbooks::ActiveModel {
-            id: NotSet,
-            user_id: Set(user_id),
-            name: Set(wordbook.name),
-            description: Set(wordbook.description),
-            cover_url: Set(None),
-            sort_order: Set(max_order),
-            created_at: Set(now),
-            updated_at: Set(now),
-        };
-
-        let saved_wb = wb.insert(state.db.as_ref()).await?;
-
-        let mut chapters_created = 0;
-        let mut words_created = 0;
-
-        for (ch_idx, chapter) in wordbook.chapters.into_iter().enumerate() {
-            let ch = entity::chapters::ActiveModel {
-                id: NotSet,
-                wordbook_id: Set(saved_wb.id),
-                name: Set(chapter.name),
-                sort_order: Set(ch_idx as i32),
-                created_at: Set(now),
-                updated_at: Set(now),
-            };
-
-            let saved_ch = ch.insert(state.db.as_ref()).await?;
-            chapters_created += 1;
-
-            for (w_idx, word) in chapter.words.into_iter().enumerate() {
-                let w = entity::words::ActiveModel {
-                    id: NotSet,
-                    chapter_id: Set(saved_ch.id),
-                    source: Set(word.source),
-                    translation: Set(word.translation),
-                    note: Set(word.note),
-                    sort_order: Set(w_idx as i32),
-                    created_at: Set(now),
-                    updated_at: Set(now),
-                };
-
-                w.insert(state.db.as_ref()).await?;
-                words_created += 1;
-            }
-        }
-
-        Ok(Json(ImportResult {
-            chapters_created,
-            words_created,
-        }))
-    }
-
-    async fn save_chapter(
-        state: &AppState,
-        wordbook_id: i32,
-        chapter: ImportChapter,
-    ) -> Result<Json<ImportResult>, AppError> {
-        let now = Utc::now().fixed_offset();
-
-        let max_order = entity::chapters::Entity::find()
-            .filter(entity::chapters::Column::WordbookId.eq(wordbook_id))
-            .order_by_desc(entity::chapters::Column::SortOrder)
-            .one(state.db.as_ref())
-            .await?
-            .map(|c| c.sort_order + 1)
-            .unwrap_or(0);
-
-        let ch = entity::chapters::ActiveModel {
-            id: NotSet,
-            wordbook_id: Set(wordbook_id),
-            name: Set(chapter.name),
-            sort_order: Set(max_order),
-            created_at: Set(now),
-            updated_at: Set(now),
-        };
-
-        let saved_ch = ch.insert(state.db.as_ref()).await?;
-
-        let mut words_created = 0;
-
-        for (w_idx, word) in chapter.words.into_iter().enumerate() {
-            let w = entity::words::ActiveModel {
-                id: NotSet,
-                chapter_id: Set(saved_ch.id),
-                source: Set(word.source),
-                translation: Set(word.translation),
-                note: Set(word.note),
-                sort_order: Set(w_idx as i32),
-                created_at: Set(now),
-                updated_at: Set(now),
-            };
-
-            w.insert(state.db.as_ref()).await?;
-            words_created += 1;
-        }
-
-        Ok(Json(ImportResult {
-            chapters_created: 1,
-            words_created,
-        }))
-    }
 }
\ No newline at end of file
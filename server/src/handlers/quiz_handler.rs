@@ -0,0 +1,391 @@
+use std::collections::HashMap;
+
+use axum::extract::Path;
+use axum::extract::State;
+use axum::Json;
+use chrono::Utc;
+use sea_orm::ActiveModelTrait;
+use sea_orm::ActiveValue::NotSet;
+use sea_orm::ColumnTrait;
+use sea_orm::ConnectionTrait;
+use sea_orm::EntityTrait;
+use sea_orm::QueryFilter;
+use sea_orm::QueryOrder;
+use sea_orm::Set;
+use sea_orm::TransactionTrait;
+use serde::Deserialize;
+use serde::Serialize;
+use tower_sessions::Session;
+use validator::Validate;
+
+use crate::auth::session::UserSession;
+use crate::error::AppError;
+use crate::handlers::word_handler::WordHandler;
+use crate::quiz::answer::AnswerMatcher;
+use crate::quiz::builder::QuestionBuilder;
+use crate::quiz::builder::QuizMode;
+use crate::review::log::ReviewLogEntry;
+use crate::review::log::ReviewLogger;
+use crate::review::log::ACTIVITY_QUIZ;
+use crate::scope::WordScope;
+use crate::shuffle::Shuffler;
+use crate::state::AppState;
+
+const DEFAULT_QUESTION_COUNT: usize = 20;
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateQuizRequest {
+    pub mode: QuizMode,
+    pub wordbook_id: Option<i32>,
+    pub chapter_id: Option<i32>,
+    #[serde(default)]
+    pub tag_ids: Vec<i32>,
+    #[validate(range(min = 1, max = 200))]
+    pub count: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SubmitQuizRequest {
+    pub answers: Vec<QuizAnswer>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QuizAnswer {
+    pub question_id: i32,
+    pub answer: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct QuizSummaryResponse {
+    pub id: i32,
+    pub mode: String,
+    pub wordbook_id: Option<i32>,
+    pub chapter_id: Option<i32>,
+    pub total_questions: i32,
+    pub correct_count: i32,
+    pub score: f64,
+    pub completed_at: Option<String>,
+    pub created_at: String,
+}
+
+impl From<entity::quiz_sessions::Model> for QuizSummaryResponse {
+    fn from(session: entity::quiz_sessions::Model) -> Self {
+        let score = if session.total_questions > 0 {
+            f64::from(session.correct_count) * 100.0 / f64::from(session.total_questions)
+        } else {
+            0.0
+        };
+
+        Self {
+            id: session.id,
+            mode: session.mode,
+            wordbook_id: session.wordbook_id,
+            chapter_id: session.chapter_id,
+            total_questions: session.total_questions,
+            correct_count: session.correct_count,
+            score,
+            completed_at: session.completed_at.map(|t| t.to_rfc3339()),
+            created_at: session.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct QuizQuestionResponse {
+    pub id: i32,
+    pub word_id: Option<i32>,
+    pub position: i32,
+    pub prompt: String,
+    // Dictation questions carry the word for speech synthesis; clients play it
+    // instead of displaying it.
+    pub speech: Option<String>,
+    pub options: Option<Vec<String>>,
+    pub expected_answer: Option<String>,
+    pub user_answer: Option<String>,
+    pub is_correct: Option<bool>,
+}
+
+impl QuizQuestionResponse {
+    fn new(question: entity::quiz_questions::Model, reveal_answer: bool, spoken: bool) -> Self {
+        Self {
+            id: question.id,
+            word_id: question.word_id,
+            position: question.position,
+            prompt: question.prompt,
+            speech: spoken.then(|| question.expected_answer.clone()),
+            options: question
+                .options
+                .and_then(|options| serde_json::from_str(&options).ok()),
+            expected_answer: reveal_answer.then_some(question.expected_answer),
+            user_answer: question.user_answer,
+            is_correct: question.is_correct,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct QuizSessionResponse {
+    #[serde(flatten)]
+    pub summary: QuizSummaryResponse,
+    pub questions: Vec<QuizQuestionResponse>,
+}
+
+impl QuizSessionResponse {
+    fn new(
+        session: entity::quiz_sessions::Model,
+        questions: Vec<entity::quiz_questions::Model>,
+    ) -> Self {
+        let reveal_answer = session.completed_at.is_some();
+        let spoken = session.mode == QuizMode::Dictation.as_str();
+        Self {
+            summary: QuizSummaryResponse::from(session),
+            questions: questions
+                .into_iter()
+                .map(|q| QuizQuestionResponse::new(q, reveal_answer, spoken))
+                .collect(),
+        }
+    }
+}
+
+pub struct QuizHandler;
+
+impl QuizHandler {
+    async fn get_user_id(session: &Session) -> Result<i32, AppError> {
+        UserSession::get(session)
+            .await?
+            .ok_or(AppError::Unauthorized)
+    }
+
+    async fn find_session(
+        state: &AppState,
+        user_id: i32,
+        id: i32,
+    ) -> Result<entity::quiz_sessions::Model, AppError> {
+        entity::quiz_sessions::Entity::find_by_id(id)
+            .filter(entity::quiz_sessions::Column::UserId.eq(user_id))
+            .one(state.db.as_ref())
+            .await?
+            .ok_or_else(|| AppError::NotFound("Quiz not found".to_string()))
+    }
+
+    async fn find_questions<C: ConnectionTrait>(
+        db: &C,
+        session_id: i32,
+    ) -> Result<Vec<entity::quiz_questions::Model>, AppError> {
+        Ok(entity::quiz_questions::Entity::find()
+            .filter(entity::quiz_questions::Column::SessionId.eq(session_id))
+            .order_by_asc(entity::quiz_questions::Column::Position)
+            .all(db)
+            .await?)
+    }
+
+    pub async fn list(
+        State(state): State<AppState>,
+        session: Session,
+    ) -> Result<Json<Vec<QuizSummaryResponse>>, AppError> {
+        let user_id = Self::get_user_id(&session).await?;
+
+        let sessions = entity::quiz_sessions::Entity::find()
+            .filter(entity::quiz_sessions::Column::UserId.eq(user_id))
+            .order_by_desc(entity::quiz_sessions::Column::CreatedAt)
+            .all(state.db.as_ref())
+            .await?;
+
+        Ok(Json(
+            sessions.into_iter().map(QuizSummaryResponse::from).collect(),
+        ))
+    }
+
+    pub async fn get(
+        State(state): State<AppState>,
+        session: Session,
+        Path(id): Path<i32>,
+    ) -> Result<Json<QuizSessionResponse>, AppError> {
+        let user_id = Self::get_user_id(&session).await?;
+
+        let quiz = Self::find_session(&state, user_id, id).await?;
+        let questions = Self::find_questions(state.db.as_ref(), quiz.id).await?;
+
+        Ok(Json(QuizSessionResponse::new(quiz, questions)))
+    }
+
+    pub async fn create(
+        State(state): State<AppState>,
+        session: Session,
+        Json(req): Json<CreateQuizRequest>,
+    ) -> Result<Json<QuizSessionResponse>, AppError> {
+        req.validate()
+            .map_err(|e| AppError::Validation(e.to_string()))?;
+
+        let user_id = Self::get_user_id(&session).await?;
+
+        let chapter_ids =
+            WordScope::chapter_ids(state.db.as_ref(), user_id, req.wordbook_id, req.chapter_id)
+                .await?;
+
+        let pool = entity::words::Entity::find()
+            .filter(entity::words::Column::ChapterId.is_in(chapter_ids.clone()))
+            .order_by_asc(entity::words::Column::SortOrder)
+            .all(state.db.as_ref())
+            .await?;
+
+        let query = entity::words::Entity::find()
+            .filter(entity::words::Column::ChapterId.is_in(chapter_ids));
+        let mut words = WordHandler::filter_by_tags(&state, query, req.tag_ids.clone())
+            .await?
+            .all(state.db.as_ref())
+            .await?;
+
+        if words.is_empty() {
+            return Err(AppError::Validation(
+                "No words available for quiz".to_string(),
+            ));
+        }
+        if req.mode == QuizMode::MultipleChoice && pool.len() < 2 {
+            return Err(AppError::Validation(
+                "Multiple choice requires at least two words".to_string(),
+            ));
+        }
+
+        let shuffler = Shuffler::new();
+        shuffler.shuffle_by(&mut words, |w| w.id);
+        words.truncate(req.count.unwrap_or(DEFAULT_QUESTION_COUNT));
+
+        let drafts = QuestionBuilder::build(req.mode, &words, &pool, &shuffler);
+        let now = Utc::now().fixed_offset();
+
+        let tag_ids = (!req.tag_ids.is_empty()).then(|| {
+            req.tag_ids
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        });
+
+        // A session is saved with all of its questions or not at all.
+        let txn = state.db.begin().await?;
+        let quiz = entity::quiz_sessions::ActiveModel {
+            id: NotSet,
+            user_id: Set(user_id),
+            mode: Set(req.mode.as_str().to_string()),
+            wordbook_id: Set(req.wordbook_id),
+            chapter_id: Set(req.chapter_id),
+            tag_ids: Set(tag_ids),
+            total_questions: Set(drafts.len() as i32),
+            correct_count: Set(0),
+            completed_at: Set(None),
+            created_at: Set(now),
+        }
+        .insert(&txn)
+        .await?;
+
+        let questions: Vec<entity::quiz_questions::ActiveModel> = drafts
+            .into_iter()
+            .enumerate()
+            .map(|(idx, draft)| entity::quiz_questions::ActiveModel {
+                id: NotSet,
+                session_id: Set(quiz.id),
+                word_id: Set(Some(draft.word_id)),
+                position: Set(idx as i32),
+                prompt: Set(draft.prompt),
+                options: Set(draft
+                    .options
+                    .and_then(|options| serde_json::to_string(&options).ok())),
+                expected_answer: Set(draft.expected_answer),
+                user_answer: Set(None),
+                is_correct: Set(None),
+                answered_at: Set(None),
+            })
+            .collect();
+
+        entity::quiz_questions::Entity::insert_many(questions)
+            .exec(&txn)
+            .await?;
+        txn.commit().await?;
+
+        let questions = Self::find_questions(state.db.as_ref(), quiz.id).await?;
+        Ok(Json(QuizSessionResponse::new(quiz, questions)))
+    }
+
+    pub async fn submit(
+        State(state): State<AppState>,
+        session: Session,
+        Path(id): Path<i32>,
+        Json(req): Json<SubmitQuizRequest>,
+    ) -> Result<Json<QuizSessionResponse>, AppError> {
+        let user_id = Self::get_user_id(&session).await?;
+
+        let quiz = Self::find_session(&state, user_id, id).await?;
+        if quiz.completed_at.is_some() {
+            return Err(AppError::Conflict("Quiz already submitted".to_string()));
+        }
+
+        let mut answers: HashMap<i32, String> = req
+            .answers
+            .into_iter()
+            .map(|a| (a.question_id, a.answer))
+            .collect();
+
+        let now = Utc::now().fixed_offset();
+        let mut correct_count = 0;
+        let mut log_entries = Vec::new();
+
+        // Completing the session claims it: a concurrent submit finds it completed and
+        // stops, and each question is only scored while it is unanswered.
+        let txn = state.db.begin().await?;
+        let claimed = entity::quiz_sessions::Entity::update_many()
+            .set(entity::quiz_sessions::ActiveModel {
+                completed_at: Set(Some(now)),
+                ..Default::default()
+            })
+            .filter(entity::quiz_sessions::Column::Id.eq(quiz.id))
+            .filter(entity::quiz_sessions::Column::CompletedAt.is_null())
+            .exec(&txn)
+            .await?;
+        if claimed.rows_affected == 0 {
+            return Err(AppError::Conflict("Quiz already submitted".to_string()));
+        }
+
+        for question in Self::find_questions(&txn, quiz.id).await? {
+            let answer = answers.remove(&question.id).unwrap_or_default();
+            let is_correct = AnswerMatcher::is_correct(&question.expected_answer, &answer);
+
+            let answered = entity::quiz_questions::Entity::update_many()
+                .set(entity::quiz_questions::ActiveModel {
+                    user_answer: Set(Some(answer)),
+                    is_correct: Set(Some(is_correct)),
+                    answered_at: Set(Some(now)),
+                    ..Default::default()
+                })
+                .filter(entity::quiz_questions::Column::Id.eq(question.id))
+                .filter(entity::quiz_questions::Column::AnsweredAt.is_null())
+                .exec(&txn)
+                .await?;
+            if answered.rows_affected == 0 {
+                continue;
+            }
+
+            if is_correct {
+                correct_count += 1;
+            }
+            if let Some(word_id) = question.word_id {
+                log_entries.push(ReviewLogEntry {
+                    word_id,
+                    grade: None,
+                    is_correct,
+                });
+            }
+        }
+
+        let mut active: entity::quiz_sessions::ActiveModel = quiz.into();
+        active.correct_count = Set(correct_count);
+        active.completed_at = Set(Some(now));
+        let quiz = active.update(&txn).await?;
+
+        ReviewLogger::record(&txn, user_id, ACTIVITY_QUIZ, log_entries, now).await?;
+        txn.commit().await?;
+
+        let questions = Self::find_questions(state.db.as_ref(), quiz.id).await?;
+        Ok(Json(QuizSessionResponse::new(quiz, questions)))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,236 @@
+use axum::extract::Path;
+use axum::extract::Query;
+use axum::extract::State;
+use axum::Json;
+use chrono::Utc;
+use sea_orm::sea_query::Query as SubQuery;
+use sea_orm::ActiveModelTrait;
+use sea_orm::ActiveValue::NotSet;
+use sea_orm::ColumnTrait;
+use sea_orm::EntityTrait;
+use sea_orm::QueryFilter;
+use sea_orm::QueryOrder;
+use sea_orm::QuerySelect;
+use sea_orm::Set;
+use sea_orm::TransactionTrait;
+use serde::Deserialize;
+use serde::Serialize;
+use tower_sessions::Session;
+use validator::Validate;
+
+use crate::auth::session::UserSession;
+use crate::error::AppError;
+use crate::review::log::ReviewLogEntry;
+use crate::review::log::ReviewLogger;
+use crate::review::log::ACTIVITY_REVIEW;
+use crate::review::scheduler::ReviewSchedule;
+use crate::review::scheduler::Sm2Scheduler;
+use crate::scope::WordScope;
+use crate::state::AppState;
+
+const DEFAULT_DUE_LIMIT: u64 = 50;
+const MAX_DUE_LIMIT: u64 = 500;
+
+#[derive(Debug, Deserialize)]
+pub struct DueQueryParams {
+    pub wordbook_id: Option<i32>,
+    pub chapter_id: Option<i32>,
+    pub limit: Option<u64>,
+    pub include_new: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct GradeRequest {
+    #[validate(range(min = 0, max = 5))]
+    pub grade: u8,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReviewStateResponse {
+    pub word_id: i32,
+    pub ease_factor: f64,
+    pub interval_days: i32,
+    pub repetitions: i32,
+    pub lapses: i32,
+    pub due_at: String,
+    pub last_reviewed_at: Option<String>,
+}
+
+impl From<entity::review_states::Model> for ReviewStateResponse {
+    fn from(state: entity::review_states::Model) -> Self {
+        Self {
+            word_id: state.word_id,
+            ease_factor: state.ease_factor,
+            interval_days: state.interval_days,
+            repetitions: state.repetitions,
+            lapses: state.lapses,
+            due_at: state.due_at.to_rfc3339(),
+            last_reviewed_at: state.last_reviewed_at.map(|t| t.to_rfc3339()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DueWordResponse {
+    pub id: i32,
+    pub chapter_id: i32,
+    pub source: String,
+    pub translation: String,
+    pub note: Option<String>,
+    pub review: Option<ReviewStateResponse>,
+}
+
+impl DueWordResponse {
+    fn new(word: entity::words::Model, review: Option<entity::review_states::Model>) -> Self {
+        Self {
+            id: word.id,
+            chapter_id: word.chapter_id,
+            source: word.source,
+            translation: word.translation,
+            note: word.note,
+            review: review.map(ReviewStateResponse::from),
+        }
+    }
+}
+
+pub struct ReviewHandler;
+
+impl ReviewHandler {
+    async fn get_user_id(session: &Session) -> Result<i32, AppError> {
+        UserSession::get(session)
+            .await?
+            .ok_or(AppError::Unauthorized)
+    }
+
+    pub async fn due(
+        State(state): State<AppState>,
+        session: Session,
+        Query(params): Query<DueQueryParams>,
+    ) -> Result<Json<Vec<DueWordResponse>>, AppError> {
+        let user_id = Self::get_user_id(&session).await?;
+
+        let chapter_ids =
+            WordScope::chapter_ids(state.db.as_ref(), user_id, params.wordbook_id, params.chapter_id)
+                .await?;
+        if chapter_ids.is_empty() {
+            return Ok(Json(vec![]));
+        }
+
+        let limit = params.limit.unwrap_or(DEFAULT_DUE_LIMIT).clamp(1, MAX_DUE_LIMIT);
+        let now = Utc::now().fixed_offset();
+
+        let due = entity::review_states::Entity::find()
+            .find_also_related(entity::words::Entity)
+            .filter(entity::review_states::Column::UserId.eq(user_id))
+            .filter(entity::review_states::Column::DueAt.lte(now))
+            .filter(entity::words::Column::ChapterId.is_in(chapter_ids.clone()))
+            .order_by_asc(entity::review_states::Column::DueAt)
+            .limit(limit)
+            .all(state.db.as_ref())
+            .await?;
+
+        let mut responses: Vec<DueWordResponse> = due
+            .into_iter()
+            .filter_map(|(review, word)| word.map(|w| DueWordResponse::new(w, Some(review))))
+            .collect();
+
+        let remaining = limit.saturating_sub(responses.len() as u64);
+        if params.include_new.unwrap_or(true) && remaining > 0 {
+            let reviewed_word_ids = SubQuery::select()
+                .column(entity::review_states::Column::WordId)
+                .from(entity::review_states::Entity)
+                .and_where(entity::review_states::Column::UserId.eq(user_id))
+                .to_owned();
+
+            let new_words = entity::words::Entity::find()
+                .filter(entity::words::Column::ChapterId.is_in(chapter_ids))
+                .filter(entity::words::Column::Id.not_in_subquery(reviewed_word_ids))
+                .order_by_asc(entity::words::Column::ChapterId)
+                .order_by_asc(entity::words::Column::SortOrder)
+                .limit(remaining)
+                .all(state.db.as_ref())
+                .await?;
+
+            responses.extend(new_words.into_iter().map(|w| DueWordResponse::new(w, None)));
+        }
+
+        Ok(Json(responses))
+    }
+
+    pub async fn grade(
+        State(state): State<AppState>,
+        session: Session,
+        Path(word_id): Path<i32>,
+        Json(req): Json<GradeRequest>,
+    ) -> Result<Json<ReviewStateResponse>, AppError> {
+        req.validate()
+            .map_err(|e| AppError::Validation(e.to_string()))?;
+
+        let user_id = Self::get_user_id(&session).await?;
+        WordScope::find_word(state.db.as_ref(), user_id, word_id).await?;
+
+        let now = Utc::now().fixed_offset();
+
+        // The schedule only advances together with its log entry.
+        let txn = state.db.begin().await?;
+        let existing = entity::review_states::Entity::find()
+            .filter(entity::review_states::Column::UserId.eq(user_id))
+            .filter(entity::review_states::Column::WordId.eq(word_id))
+            .one(&txn)
+            .await?;
+
+        let current = existing
+            .as_ref()
+            .map(ReviewSchedule::from)
+            .unwrap_or_default();
+        let next = Sm2Scheduler::schedule(current, req.grade);
+        let due_at = Sm2Scheduler::due_at(now, &next);
+
+        let review = match existing {
+            Some(model) => {
+                let mut active: entity::review_states::ActiveModel = model.into();
+                active.ease_factor = Set(next.ease_factor);
+                active.interval_days = Set(next.interval_days);
+                active.repetitions = Set(next.repetitions);
+                active.lapses = Set(next.lapses);
+                active.due_at = Set(due_at);
+                active.last_reviewed_at = Set(Some(now));
+                active.updated_at = Set(now);
+                active.update(&txn).await?
+            }
+            None => {
+                entity::review_states::ActiveModel {
+                    id: NotSet,
+                    user_id: Set(user_id),
+                    word_id: Set(word_id),
+                    ease_factor: Set(next.ease_factor),
+                    interval_days: Set(next.interval_days),
+                    repetitions: Set(next.repetitions),
+                    lapses: Set(next.lapses),
+                    due_at: Set(due_at),
+                    last_reviewed_at: Set(Some(now)),
+                    created_at: Set(now),
+                    updated_at: Set(now),
+                }
+                .insert(&txn)
+                .await?
+            }
+        };
+
+        ReviewLogger::record(
+            &txn,
+            user_id,
+            ACTIVITY_REVIEW,
+            vec![ReviewLogEntry {
+                word_id,
+                grade: Some(req.grade),
+                is_correct: Sm2Scheduler::is_passing(req.grade),
+            }],
+            now,
+        )
+        .await?;
+        txn.commit().await?;
+
+        Ok(Json(ReviewStateResponse::from(review)))
+    }
+}
\ No newline at end of file
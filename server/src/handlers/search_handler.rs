@@ -0,0 +1,159 @@
+use axum::extract::Query;
+use axum::extract::State;
+use axum::Json;
+use sea_orm::DbBackend;
+use sea_orm::FromQueryResult;
+use sea_orm::Statement;
+use sea_orm::Value;
+use serde::Deserialize;
+use serde::Serialize;
+use tower_sessions::Session;
+
+use crate::auth::session::UserSession;
+use crate::error::AppError;
+use crate::search::SearchTerms;
+use crate::state::AppState;
+
+const DEFAULT_SEARCH_LIMIT: u64 = 50;
+const MAX_SEARCH_LIMIT: u64 = 200;
+const BM25_WEIGHTS: &str = "10.0, 5.0, 1.0";
+
+#[derive(Debug, Deserialize)]
+pub struct SearchQueryParams {
+    pub q: String,
+    pub wordbook_id: Option<i32>,
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct SearchRow {
+    word_id: i32,
+    source: String,
+    translation: String,
+    note: Option<String>,
+    chapter_id: i32,
+    chapter_name: String,
+    wordbook_id: i32,
+    wordbook_name: String,
+    rank: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SearchHighlight {
+    pub source: String,
+    pub translation: String,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SearchResult {
+    pub word_id: i32,
+    pub source: String,
+    pub translation: String,
+    pub note: Option<String>,
+    pub chapter_id: i32,
+    pub chapter_name: String,
+    pub wordbook_id: i32,
+    pub wordbook_name: String,
+    pub highlight: SearchHighlight,
+    pub rank: f64,
+}
+
+pub struct SearchHandler;
+
+impl SearchHandler {
+    async fn get_user_id(session: &Session) -> Result<i32, AppError> {
+        UserSession::get(session)
+            .await?
+            .ok_or(AppError::Unauthorized)
+    }
+
+    pub async fn search(
+        State(state): State<AppState>,
+        session: Session,
+        Query(params): Query<SearchQueryParams>,
+    ) -> Result<Json<Vec<SearchResult>>, AppError> {
+        let user_id = Self::get_user_id(&session).await?;
+
+        let terms = SearchTerms::parse(&params.q);
+        if terms.is_empty() {
+            return Ok(Json(vec![]));
+        }
+
+        let limit = params.limit.unwrap_or(DEFAULT_SEARCH_LIMIT).clamp(1, MAX_SEARCH_LIMIT);
+        let match_expression = terms.match_expression();
+
+        let rank = if match_expression.is_some() {
+            format!("bm25(words_fts, {})", BM25_WEIGHTS)
+        } else {
+            "0.0".to_string()
+        };
+
+        let mut sql = format!(
+            "SELECT w.id AS word_id, w.source, w.translation, w.note, \
+             c.id AS chapter_id, c.name AS chapter_name, \
+             b.id AS wordbook_id, b.name AS wordbook_name, {} AS rank \
+             FROM words_fts \
+             JOIN words w ON w.id = words_fts.rowid \
+             JOIN chapters c ON c.id = w.chapter_id \
+             JOIN wordbooks b ON b.id = c.wordbook_id \
+             WHERE b.user_id = ?",
+            rank
+        );
+        let mut values: Vec<Value> = vec![user_id.into()];
+
+        if let Some(wordbook_id) = params.wordbook_id {
+            sql.push_str(" AND b.id = ?");
+            values.push(wordbook_id.into());
+        }
+
+        if let Some(expression) = match_expression {
+            sql.push_str(" AND words_fts MATCH ?");
+            values.push(expression.into());
+        }
+
+        for pattern in terms.like_patterns() {
+            sql.push_str(
+                " AND (w.source LIKE ? ESCAPE '\\' OR w.translation LIKE ? ESCAPE '\\' \
+                 OR w.note LIKE ? ESCAPE '\\')",
+            );
+            values.extend([pattern.clone().into(), pattern.clone().into(), pattern.into()]);
+        }
+
+        sql.push_str(
+            " ORDER BY CASE WHEN w.source LIKE ? ESCAPE '\\' THEN 0 ELSE 1 END, rank, \
+             length(w.source) LIMIT ?",
+        );
+        values.push(terms.prefix_pattern().into());
+        values.push(limit.into());
+
+        let rows = SearchRow::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Sqlite,
+            sql,
+            values,
+        ))
+        .all(state.db.as_ref())
+        .await?;
+
+        Ok(Json(
+            rows.into_iter()
+                .map(|row| SearchResult {
+                    highlight: SearchHighlight {
+                        source: terms.highlight(&row.source),
+                        translation: terms.highlight(&row.translation),
+                        note: row.note.as_deref().map(|note| terms.snippet(note)),
+                    },
+                    word_id: row.word_id,
+                    source: row.source,
+                    translation: row.translation,
+                    note: row.note,
+                    chapter_id: row.chapter_id,
+                    chapter_name: row.chapter_name,
+                    wordbook_id: row.wordbook_id,
+                    wordbook_name: row.wordbook_name,
+                    rank: row.rank,
+                })
+                .collect(),
+        ))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,300 @@
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use axum::extract::Query;
+use axum::extract::State;
+use axum::Json;
+use chrono::DateTime;
+use chrono::Days;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::Utc;
+use sea_orm::ColumnTrait;
+use sea_orm::EntityTrait;
+use sea_orm::QueryFilter;
+use sea_orm::QueryOrder;
+use sea_orm::QuerySelect;
+use sea_orm::sea_query::Expr;
+use sea_orm::sea_query::ExprTrait;
+use serde::Deserialize;
+use serde::Serialize;
+use tower_sessions::Session;
+
+use crate::auth::session::UserSession;
+use crate::error::AppError;
+use crate::state::AppState;
+use crate::stats::DailyStat;
+use crate::stats::HeatmapCell;
+use crate::stats::LogPoint;
+use crate::stats::RetentionPoint;
+use crate::stats::StatsCalculator;
+use crate::stats::StreakStat;
+use crate::time::ClientTime;
+
+const DEFAULT_DAILY_DAYS: u64 = 30;
+const DEFAULT_HEATMAP_DAYS: u64 = 365;
+const MAX_DAYS: u64 = 730;
+
+#[derive(Debug, Deserialize)]
+pub struct StatsQuery {
+    pub days: Option<u64>,
+    pub tz_offset: Option<i32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChapterAccuracy {
+    pub chapter_id: i32,
+    pub chapter_name: String,
+    pub wordbook_id: i32,
+    pub total: usize,
+    pub correct: usize,
+    pub accuracy: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TagAccuracy {
+    pub tag_id: i32,
+    pub name: String,
+    pub color: Option<String>,
+    pub total: usize,
+    pub correct: usize,
+    pub accuracy: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AccuracyResponse {
+    pub chapters: Vec<ChapterAccuracy>,
+    pub tags: Vec<TagAccuracy>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HeatmapResponse {
+    pub from: String,
+    pub to: String,
+    pub max_count: usize,
+    pub days: Vec<HeatmapCell>,
+}
+
+pub struct StatsHandler;
+
+impl StatsHandler {
+    async fn get_user_id(session: &Session) -> Result<i32, AppError> {
+        UserSession::get(session)
+            .await?
+            .ok_or(AppError::Unauthorized)
+    }
+
+    fn calculator(query: &StatsQuery) -> Result<(StatsCalculator, NaiveDate), AppError> {
+        let offset = ClientTime::offset(query.tz_offset)?;
+        let calculator = StatsCalculator::new(offset);
+        let today = calculator.local_date(Utc::now().fixed_offset());
+        Ok((calculator, today))
+    }
+
+    fn date_range(query: &StatsQuery, default_days: u64, today: NaiveDate) -> (NaiveDate, NaiveDate) {
+        let days = query.days.unwrap_or(default_days).clamp(1, MAX_DAYS);
+        let from = today.checked_sub_days(Days::new(days - 1)).unwrap_or(today);
+        (from, today)
+    }
+
+    // Logs from `since` on, or the whole history without it.
+    async fn load_logs(
+        state: &AppState,
+        user_id: i32,
+        since: Option<DateTime<FixedOffset>>,
+    ) -> Result<Vec<LogPoint>, AppError> {
+        let mut query = entity::review_logs::Entity::find()
+            .filter(entity::review_logs::Column::UserId.eq(user_id));
+        if let Some(since) = since {
+            query = query.filter(entity::review_logs::Column::ReviewedAt.gte(since));
+        }
+        Ok(query
+            .order_by_asc(entity::review_logs::Column::ReviewedAt)
+            .all(state.db.as_ref())
+            .await?
+            .into_iter()
+            .map(LogPoint::from)
+            .collect())
+    }
+
+    // The first review of each word first reviewed from `since` on.
+    async fn load_first_reviews(
+        state: &AppState,
+        user_id: i32,
+        since: DateTime<FixedOffset>,
+    ) -> Result<Vec<DateTime<FixedOffset>>, AppError> {
+        let first_review = Expr::col(entity::review_logs::Column::ReviewedAt).min();
+        Ok(entity::review_logs::Entity::find()
+            .select_only()
+            .expr(first_review.clone())
+            .filter(entity::review_logs::Column::UserId.eq(user_id))
+            .filter(entity::review_logs::Column::WordId.is_not_null())
+            .group_by(entity::review_logs::Column::WordId)
+            .having(first_review.gte(since))
+            .into_tuple()
+            .all(state.db.as_ref())
+            .await?)
+    }
+
+    // Local dates with at least one review, grouped in SQL so the streak never loads
+    // individual logs.
+    async fn load_active_days(
+        state: &AppState,
+        user_id: i32,
+        calculator: &StatsCalculator,
+    ) -> Result<BTreeSet<NaiveDate>, AppError> {
+        let days: Vec<String> = entity::review_logs::Entity::find()
+            .select_only()
+            .expr(Expr::cust_with_values(
+                "date(reviewed_at, ?)",
+                [calculator.date_modifier()],
+            ))
+            .distinct()
+            .filter(entity::review_logs::Column::UserId.eq(user_id))
+            .into_tuple()
+            .all(state.db.as_ref())
+            .await?;
+        Ok(days
+            .iter()
+            .filter_map(|day| NaiveDate::parse_from_str(day, "%Y-%m-%d").ok())
+            .collect())
+    }
+
+    pub async fn daily(
+        State(state): State<AppState>,
+        session: Session,
+        Query(query): Query<StatsQuery>,
+    ) -> Result<Json<Vec<DailyStat>>, AppError> {
+        let user_id = Self::get_user_id(&session).await?;
+        let (calculator, today) = Self::calculator(&query)?;
+        let (from, to) = Self::date_range(&query, DEFAULT_DAILY_DAYS, today);
+
+        let since = calculator.read_from(from);
+        let logs = Self::load_logs(&state, user_id, Some(since)).await?;
+        let first_reviews = Self::load_first_reviews(&state, user_id, since).await?;
+        Ok(Json(calculator.daily(&logs, &first_reviews, from, to)))
+    }
+
+    pub async fn streak(
+        State(state): State<AppState>,
+        session: Session,
+        Query(query): Query<StatsQuery>,
+    ) -> Result<Json<StreakStat>, AppError> {
+        let user_id = Self::get_user_id(&session).await?;
+        let (calculator, today) = Self::calculator(&query)?;
+
+        let active = Self::load_active_days(&state, user_id, &calculator).await?;
+        Ok(Json(StatsCalculator::streak(&active, today)))
+    }
+
+    pub async fn retention(
+        State(state): State<AppState>,
+        session: Session,
+        Query(query): Query<StatsQuery>,
+    ) -> Result<Json<Vec<RetentionPoint>>, AppError> {
+        let user_id = Self::get_user_id(&session).await?;
+        let (calculator, _) = Self::calculator(&query)?;
+
+        // Intervals between reviews can span any length of history.
+        let logs = Self::load_logs(&state, user_id, None).await?;
+        Ok(Json(calculator.retention(&logs)))
+    }
+
+    pub async fn heatmap(
+        State(state): State<AppState>,
+        session: Session,
+        Query(query): Query<StatsQuery>,
+    ) -> Result<Json<HeatmapResponse>, AppError> {
+        let user_id = Self::get_user_id(&session).await?;
+        let (calculator, today) = Self::calculator(&query)?;
+        let (from, to) = Self::date_range(&query, DEFAULT_HEATMAP_DAYS, today);
+
+        let logs = Self::load_logs(&state, user_id, Some(calculator.read_from(from))).await?;
+        let days = calculator.heatmap(&logs, from, to);
+
+        Ok(Json(HeatmapResponse {
+            from: StatsCalculator::format_date(from),
+            to: StatsCalculator::format_date(to),
+            max_count: days.iter().map(|d| d.count).max().unwrap_or(0),
+            days,
+        }))
+    }
+
+    pub async fn accuracy(
+        State(state): State<AppState>,
+        session: Session,
+    ) -> Result<Json<AccuracyResponse>, AppError> {
+        let user_id = Self::get_user_id(&session).await?;
+
+        let rows = entity::review_logs::Entity::find()
+            .find_also_related(entity::words::Entity)
+            .filter(entity::review_logs::Column::UserId.eq(user_id))
+            .all(state.db.as_ref())
+            .await?;
+
+        let word_chapters: HashMap<i32, i32> = rows
+            .iter()
+            .filter_map(|(_, word)| word.as_ref().map(|w| (w.id, w.chapter_id)))
+            .collect();
+        let logs: Vec<LogPoint> = rows.into_iter().map(|(log, _)| LogPoint::from(log)).collect();
+
+        let chapter_counts = StatsCalculator::accuracy_by(&logs, |word_id| {
+            word_chapters.get(&word_id).copied().into_iter().collect()
+        });
+
+        let chapters = entity::chapters::Entity::find()
+            .filter(entity::chapters::Column::Id.is_in(chapter_counts.keys().copied()))
+            .order_by_asc(entity::chapters::Column::WordbookId)
+            .order_by_asc(entity::chapters::Column::SortOrder)
+            .all(state.db.as_ref())
+            .await?
+            .into_iter()
+            .map(|ch| {
+                let count = chapter_counts[&ch.id];
+                ChapterAccuracy {
+                    chapter_id: ch.id,
+                    chapter_name: ch.name,
+                    wordbook_id: ch.wordbook_id,
+                    total: count.total,
+                    correct: count.correct,
+                    accuracy: count.rate(),
+                }
+            })
+            .collect();
+
+        let tags = entity::tags::Entity::find()
+            .filter(entity::tags::Column::UserId.eq(user_id))
+            .all(state.db.as_ref())
+            .await?;
+
+        let mut word_tags: HashMap<i32, Vec<i32>> = HashMap::new();
+        for wt in entity::word_tags::Entity::find()
+            .filter(entity::word_tags::Column::TagId.is_in(tags.iter().map(|t| t.id)))
+            .all(state.db.as_ref())
+            .await?
+        {
+            word_tags.entry(wt.word_id).or_default().push(wt.tag_id);
+        }
+
+        let tag_counts = StatsCalculator::accuracy_by(&logs, |word_id| {
+            word_tags.get(&word_id).cloned().unwrap_or_default()
+        });
+
+        let tags = tags
+            .into_iter()
+            .filter_map(|tag| {
+                let count = tag_counts.get(&tag.id)?;
+                Some(TagAccuracy {
+                    tag_id: tag.id,
+                    name: tag.name,
+                    color: tag.color,
+                    total: count.total,
+                    correct: count.correct,
+                    accuracy: count.rate(),
+                })
+            })
+            .collect();
+
+        Ok(Json(AccuracyResponse { chapters, tags }))
+    }
+}
\ No newline at end of file
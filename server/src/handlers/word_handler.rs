@@ -10,23 +10,40 @@ use sea_orm::EntityTrait;
 use sea_orm::ModelTrait;
 use sea_orm::QueryFilter;
 use sea_orm::QueryOrder;
+use sea_orm::Select;
 use sea_orm::Set;
+use sea_orm::TransactionTrait;
 use serde::Deserialize;
 use serde::Serialize;
 use tower_sessions::Session;
 use validator::Validate;
 
 use crate::auth::session::UserSession;
+use crate::dictionary::lookup::DictionaryLookup;
 use crate::error::AppError;
+use crate::examples::ExampleStore;
+use crate::filter::WordFilterParams;
+use crate::import::data::ImportExample;
+use crate::shuffle::Shuffler;
 use crate::state::AppState;
 
 #[derive(Debug, Deserialize, Validate)]
 pub struct CreateWordRequest {
     #[validate(length(min = 1, max = 500))]
     pub source: String,
-    #[validate(length(min = 1, max = 500))]
+    #[serde(default)]
+    #[validate(length(max = 500))]
     pub translation: String,
     pub note: Option<String>,
+    #[validate(length(max = 200))]
+    pub phonetic: Option<String>,
+    #[validate(length(max = 50))]
+    pub part_of_speech: Option<String>,
+    #[serde(default)]
+    #[validate(nested, length(max = 50))]
+    pub examples: Vec<WordExampleRequest>,
+    #[serde(default)]
+    pub autofill: bool,
 }
 
 #[derive(Debug, Deserialize, Validate)]
@@ -36,12 +53,25 @@ pub struct UpdateWordRequest {
     #[validate(length(min = 1, max = 500))]
     pub translation: Option<String>,
     pub note: Option<String>,
+    #[validate(length(max = 200))]
+    pub phonetic: Option<String>,
+    #[validate(length(max = 50))]
+    pub part_of_speech: Option<String>,
+    #[validate(nested, length(max = 50))]
+    pub examples: Option<Vec<WordExampleRequest>>,
     pub sort_order: Option<i32>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct WordExampleRequest {
+    #[validate(length(min = 1, max = 1000))]
+    pub sentence: String,
+    #[validate(length(max = 1000))]
+    pub translation: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct WordQueryParams {
-    pub tag_ids: Option<String>,
     pub shuffle: Option<bool>,
 }
 
@@ -74,12 +104,22 @@ pub struct WordResponse {
     pub source: String,
     pub translation: String,
     pub note: Option<String>,
+    pub phonetic: Option<String>,
+    pub part_of_speech: Option<String>,
+    pub examples: Vec<WordExampleResponse>,
     pub sort_order: i32,
     pub tags: Vec<TagInfo>,
     pub created_at: String,
     pub updated_at: String,
 }
 
+#[derive(Debug, Serialize)]
+pub struct WordExampleResponse {
+    pub id: i32,
+    pub sentence: String,
+    pub translation: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct TagInfo {
     pub id: i32,
@@ -87,9 +127,38 @@ pub struct TagInfo {
     pub color: Option<String>,
 }
 
+impl From<WordExampleRequest> for ImportExample {
+    fn from(req: WordExampleRequest) -> Self {
+        Self {
+            sentence: req.sentence,
+            translation: req.translation.filter(|t| !t.is_empty()),
+        }
+    }
+}
+
 pub struct WordHandler;
 
 impl WordHandler {
+    pub async fn filter_by_tags(
+        state: &AppState,
+        query: Select<entity::words::Entity>,
+        tag_ids: Vec<i32>,
+    ) -> Result<Select<entity::words::Entity>, AppError> {
+        if tag_ids.is_empty() {
+            return Ok(query);
+        }
+
+        let word_ids: Vec<i32> = entity::word_tags::Entity::find()
+            .filter(entity::word_tags::Column::TagId.is_in(tag_ids))
+            .all(state.db.as_ref())
+            .await?
+            .into_iter()
+            .map(|wt| wt.word_id)
+            .collect();
+
+        Ok(query.filter(entity::words::Column::Id.is_in(word_ids)))
+    }
+
     async fn verify_chapter_ownership(
         state: &AppState,
         session: &Session,
@@ -140,12 +209,27 @@ impl WordHandler {
                 .collect()
         };
 
+        let examples = ExampleStore::load(state.db.as_ref(), vec![word.id])
+            .await?
+            .remove(&word.id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|example| WordExampleResponse {
+                id: example.id,
+                sentence: example.sentence,
+                translation: example.translation,
+            })
+            .collect();
+
         Ok(WordResponse {
             id: word.id,
             chapter_id: word.chapter_id,
             source: word.source,
             translation: word.translation,
             note: word.note,
+            phonetic: word.phonetic,
+            part_of_speech: word.part_of_speech,
+            examples,
             sort_order: word.sort_order,
             tags,
             created_at: word.created_at.to_rfc3339(),
@@ -158,48 +242,20 @@ impl WordHandler {
         session: Session,
         Path(chapter_id): Path<i32>,
         Query(params): Query<WordQueryParams>,
+        Query(filter): Query<WordFilterParams>,
     ) -> Result<Json<Vec<WordResponse>>, AppError> {
+        let filter = filter.parse()?;
         Self::verify_chapter_ownership(&state, &session, chapter_id).await?;
 
-        let mut query =
-            entity::words::Entity::find().filter(entity::words::Column::ChapterId.eq(chapter_id));
-
-        if let Some(tag_ids_str) = &params.tag_ids {
-            let tag_ids: Vec<i32> = tag_ids_str
-                .split(',')
-                .filter_map(|s| s.parse().ok())
-                .collect();
-            if !tag_ids.is_empty() {
-                let word_ids: Vec<i32> = entity::word_tags::Entity::find()
-                    .filter(entity::word_tags::Column::TagId.is_in(tag_ids))
-                    .all(state.db.as_ref())
-                    .await?
-                    .into_iter()
-                    .map(|wt| wt.word_id)
-                    .collect();
-
-                query = query.filter(entity::words::Column::Id.is_in(word_ids));
-            }
-        }
-
-        let mut words = query
+        let mut words = entity::words::Entity::find()
+            .filter(entity::words::Column::ChapterId.eq(chapter_id))
+            .filter(filter.condition())
             .order_by_asc(entity::words::Column::SortOrder)
             .all(state.db.as_ref())
             .await?;
 
         if params.shuffle.unwrap_or(false) {
-            use std::collections::hash_map::DefaultHasher;
-            use std::hash::Hash;
-            use std::hash::Hasher;
-
-            let seed = Utc::now().timestamp_millis();
-            words.sort_by(|a, b| {
-                let mut ha = DefaultHasher::new();
-                let mut hb = DefaultHasher::new();
-                (a.id, seed).hash(&mut ha);
-                (b.id, seed).hash(&mut hb);
-                ha.finish().cmp(&hb.finish())
-            });
+            Shuffler::new().shuffle_by(&mut words, |w| w.id);
         }
 
         let mut responses = Vec::with_capacity(words.len());
@@ -214,19 +270,41 @@ impl WordHandler {
         State(state): State<AppState>,
         session: Session,
         Path(chapter_id): Path<i32>,
-        Json(req): Json<CreateWordRequest>,
+        Json(mut req): Json<CreateWordRequest>,
     ) -> Result<Json<WordResponse>, AppError> {
         req.validate()
             .map_err(|e| AppError::Validation(e.to_string()))?;
 
         Self::verify_chapter_ownership(&state, &session, chapter_id).await?;
 
+        let needs_lookup = req.translation.trim().is_empty()
+            || req.phonetic.as_deref().is_none_or(str::is_empty);
+        if req.autofill
+            && needs_lookup
+            && let Some(entry) = DictionaryLookup::find(state.db.as_ref(), &req.source).await?
+        {
+            if req.translation.trim().is_empty() {
+                req.translation = DictionaryLookup::summary(&entry.translation);
+            }
+            if req.phonetic.as_deref().is_none_or(str::is_empty) {
+                req.phonetic = entry.phonetic;
+            }
+        }
+
+        if req.translation.trim().is_empty() {
+            return Err(AppError::Validation(
+                "translation: Translation is required".to_string(),
+            ));
+        }
+
         let now = Utc::now().fixed_offset();
 
+        // The word and its examples are saved together.
+        let txn = state.db.begin().await?;
         let max_order = entity::words::Entity::find()
             .filter(entity::words::Column::ChapterId.eq(chapter_id))
             .order_by_desc(entity::words::Column::SortOrder)
-            .one(state.db.as_ref())
+            .one(&txn)
             .await?
             .map(|w| w.sort_order + 1)
             .unwrap_or(0);
@@ -240,9 +318,18 @@ impl WordHandler {
             sort_order: Set(max_order),
             created_at: Set(now),
             updated_at: Set(now),
+            phonetic: Set(req.phonetic.filter(|p| !p.is_empty())),
+            part_of_speech: Set(req.part_of_speech.filter(|p| !p.is_empty())),
         };
 
-        let word = word.insert(state.db.as_ref()).await?;
+        let word = word.insert(&txn).await?;
+        ExampleStore::insert(
+            &txn,
+            word.id,
+            req.examples.into_iter().map(ImportExample::from).collect(),
+        )
+        .await?;
+        txn.commit().await?;
         Self::get_word_with_tags(&state, word).await.map(Json)
     }
 
@@ -275,11 +362,28 @@ impl WordHandler {
         if let Some(note) = req.note {
             active.note = Set(Some(note));
         }
+        if let Some(phonetic) = req.phonetic {
+            active.phonetic = Set(Some(phonetic).filter(|p| !p.is_empty()));
+        }
+        if let Some(part_of_speech) = req.part_of_speech {
+            active.part_of_speech = Set(Some(part_of_speech).filter(|p| !p.is_empty()));
+        }
         if let Some(sort_order) = req.sort_order {
             active.sort_order = Set(sort_order);
         }
 
-        let word = active.update(state.db.as_ref()).await?;
+        // Replacing examples deletes the old ones first, so both writes share a transaction.
+        let txn = state.db.begin().await?;
+        let word = active.update(&txn).await?;
+        if let Some(examples) = req.examples {
+            ExampleStore::replace(
+                &txn,
+                word.id,
+                examples.into_iter().map(ImportExample::from).collect(),
+            )
+            .await?;
+        }
+        txn.commit().await?;
         Self::get_word_with_tags(&state, word).await.map(Json)
     }
 
@@ -1,6 +1,19 @@
+pub mod anki;
+pub mod collector;
+pub mod columns;
 pub mod data;
+pub mod encoding;
 pub mod error;
+pub mod flashcard;
+pub mod jobs;
+pub mod kindle;
+pub mod markdown;
+pub mod ods;
+pub mod parser_csv;
 pub mod parser_excel;
 pub mod parser_json;
 pub mod parser_xml;
-pub mod template;
\ No newline at end of file
+pub mod temp_db;
+pub mod template;
+pub mod validate;
+pub mod writer;
\ No newline at end of file
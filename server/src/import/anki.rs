@@ -0,0 +1,603 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::io::Read;
+use std::io::Write;
+
+use chrono::Utc;
+use serde::Deserialize;
+use serde_json::json;
+use serde_json::Map;
+use serde_json::Value;
+use sha1::Digest;
+use sha1::Sha1;
+use sqlx::Connection;
+use uuid::Uuid;
+use zip::write::SimpleFileOptions;
+use zip::CompressionMethod;
+use zip::ZipArchive;
+use zip::ZipWriter;
+
+use crate::import::data::ImportChapter;
+use crate::import::data::ImportExample;
+use crate::import::data::ImportTag;
+use crate::import::data::ImportWord;
+use crate::import::data::ImportWordbook;
+use crate::import::error::ImportError;
+use crate::import::temp_db::TempDatabase;
+use crate::markup::Markup;
+
+const COLLECTION_FILE: &str = "collection.anki2";
+const COLLECTION_EXTENSION: &str = "anki2";
+const COLLECTION_FILE_V21: &str = "collection.anki21";
+const COLLECTION_FILE_V21B: &str = "collection.anki21b";
+const MEDIA_FILE: &str = "media";
+const COLLECTION_VERSION: i64 = 11;
+const FIELD_SEPARATOR: &str = "\x1f";
+const DECK_SEPARATOR: &str = "::";
+const DEFAULT_DECK_ID: i64 = 1;
+const DEFAULT_DECK_NAME: &str = "Default";
+const MODEL_NAME: &str = "Plain Word";
+const FIELD_NAMES: [&str; 6] = [
+    "Source",
+    "Translation",
+    "Note",
+    "Phonetic",
+    "PartOfSpeech",
+    "Examples",
+];
+const SOURCE_ALIASES: &[&str] = &["source", "front", "word", "expression"];
+const TRANSLATION_ALIASES: &[&str] = &["translation", "back", "meaning", "definition"];
+const NOTE_ALIASES: &[&str] = &["note", "notes"];
+const PHONETIC_ALIASES: &[&str] = &["phonetic", "pronunciation", "ipa"];
+const PART_OF_SPEECH_ALIASES: &[&str] = &["partofspeech", "pos"];
+const EXAMPLES_ALIASES: &[&str] = &["examples", "example"];
+const QUESTION_TEMPLATE: &str = "{{Source}}{{#Phonetic}}<div class=phonetic>{{Phonetic}}</div>{{/Phonetic}}";
+const ANSWER_TEMPLATE: &str = "{{FrontSide}}<hr id=answer>\
+{{#PartOfSpeech}}<i>{{PartOfSpeech}}</i> {{/PartOfSpeech}}{{Translation}}\
+{{#Note}}<div class=note>{{Note}}</div>{{/Note}}\
+{{#Examples}}<div class=examples>{{Examples}}</div>{{/Examples}}";
+const CARD_CSS: &str = ".card { font-family: arial; font-size: 20px; text-align: center; color: black; background-color: white; }\n\
+.phonetic, .note, .examples { font-size: 16px; color: #666; margin-top: 8px; }";
+const LATEX_PRE: &str = "\\documentclass[12pt]{article}\n\\special{papersize=3in,5in}\n\\usepackage[utf8]{inputenc}\n\
+\\usepackage{amssymb,amsmath}\n\\pagestyle{empty}\n\\setlength{\\parindent}{0in}\n\\begin{document}\n";
+const LATEX_POST: &str = "\\end{document}";
+const SCHEMA: &str = "
+CREATE TABLE col (
+    id integer primary key, crt integer not null, mod integer not null, scm integer not null,
+    ver integer not null, dty integer not null, usn integer not null, ls integer not null,
+    conf text not null, models text not null, decks text not null, dconf text not null,
+    tags text not null
+);
+CREATE TABLE notes (
+    id integer primary key, guid text not null, mid integer not null, mod integer not null,
+    usn integer not null, tags text not null, flds text not null, sfld integer not null,
+    csum integer not null, flags integer not null, data text not null
+);
+CREATE TABLE cards (
+    id integer primary key, nid integer not null, did integer not null, ord integer not null,
+    mod integer not null, usn integer not null, type integer not null, queue integer not null,
+    due integer not null, ivl integer not null, factor integer not null, reps integer not null,
+    lapses integer not null, left integer not null, odue integer not null, odid integer not null,
+    flags integer not null, data text not null
+);
+CREATE TABLE revlog (
+    id integer primary key, cid integer not null, usn integer not null, ease integer not null,
+    ivl integer not null, lastIvl integer not null, factor integer not null, time integer not null,
+    type integer not null
+);
+CREATE TABLE graves (usn integer not null, oid integer not null, type integer not null);
+CREATE INDEX ix_notes_usn ON notes (usn);
+CREATE INDEX ix_cards_usn ON cards (usn);
+CREATE INDEX ix_revlog_usn ON revlog (usn);
+CREATE INDEX ix_cards_nid ON cards (nid);
+CREATE INDEX ix_cards_sched ON cards (did, queue, due);
+CREATE INDEX ix_revlog_cid ON revlog (cid);
+CREATE INDEX ix_notes_csum ON notes (csum);
+";
+
+#[derive(Debug, Deserialize)]
+struct AnkiModel {
+    #[serde(default)]
+    flds: Vec<AnkiField>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnkiField {
+    name: String,
+    ord: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnkiDeck {
+    name: String,
+    #[serde(default, rename = "dyn")]
+    dynamic: Value,
+}
+
+struct NoteFields {
+    source: usize,
+    translation: Option<usize>,
+    note: Option<usize>,
+    phonetic: Option<usize>,
+    part_of_speech: Option<usize>,
+    examples: Option<usize>,
+}
+
+pub struct AnkiPackage;
+
+impl AnkiPackage {
+    pub async fn parse_wordbook(
+        data: &[u8],
+        wordbook_name: String,
+    ) -> Result<ImportWordbook, ImportError> {
+        Ok(ImportWordbook {
+            name: wordbook_name,
+            description: None,
+            chapters: Self::read(data).await?,
+        })
+    }
+
+    pub async fn parse_chapter(data: &[u8], chapter_name: String) -> Result<ImportChapter, ImportError> {
+        let words = Self::read(data)
+            .await?
+            .into_iter()
+            .flat_map(|chapter| chapter.words)
+            .collect();
+        Ok(ImportChapter::with_words(chapter_name, words))
+    }
+
+    pub async fn write_wordbook(wordbook: &ImportWordbook) -> Result<Vec<u8>, ImportError> {
+        Self::write(Some(&wordbook.name), wordbook.description.as_deref(), &wordbook.chapters).await
+    }
+
+    pub async fn write_chapter(chapter: &ImportChapter) -> Result<Vec<u8>, ImportError> {
+        Self::write(None, None, std::slice::from_ref(chapter)).await
+    }
+
+    async fn read(data: &[u8]) -> Result<Vec<ImportChapter>, ImportError> {
+        let collection = TempDatabase::new(COLLECTION_EXTENSION);
+        tokio::fs::write(&collection.path, Self::extract_collection(data)?).await?;
+        let mut conn = collection.connect(false).await.map_err(Self::collection_error)?;
+
+        let (models, decks): (String, String) = sqlx::query_as("SELECT models, decks FROM col")
+            .fetch_one(&mut conn)
+            .await.map_err(Self::collection_error)?;
+        let rows: Vec<(i64, String, String, i64)> = sqlx::query_as(
+            "SELECT n.mid, n.tags, n.flds, \
+             MIN(CASE WHEN c.odid != 0 THEN c.odid ELSE c.did END) \
+             FROM notes n JOIN cards c ON c.nid = n.id \
+             GROUP BY n.id ORDER BY MIN(c.due), n.id",
+        )
+        .fetch_all(&mut conn)
+        .await.map_err(Self::collection_error)?;
+        conn.close().await.map_err(Self::collection_error)?;
+
+        let fields: HashMap<i64, NoteFields> = serde_json::from_str::<HashMap<String, AnkiModel>>(&models)?
+            .into_iter()
+            .filter_map(|(id, model)| Some((id.parse().ok()?, NoteFields::from_model(&model))))
+            .collect();
+        let decks: HashMap<i64, AnkiDeck> = serde_json::from_str::<HashMap<String, AnkiDeck>>(&decks)?
+            .into_iter()
+            .filter_map(|(id, deck)| Some((id.parse().ok()?, deck)))
+            .collect();
+
+        let mut deck_words: BTreeMap<i64, Vec<ImportWord>> = BTreeMap::new();
+        for (model_id, tags, flds, deck_id) in rows {
+            let word = match fields.get(&model_id) {
+                Some(fields) => fields.read(&flds, &tags),
+                None => NoteFields::positional().read(&flds, &tags),
+            };
+            if let Some(word) = word {
+                deck_words.entry(deck_id).or_default().push(word);
+            }
+        }
+
+        let mut deck_ids: BTreeSet<i64> = decks.keys().copied().collect();
+        deck_ids.extend(deck_words.keys());
+
+        let chapters: Vec<ImportChapter> = deck_ids
+            .into_iter()
+            .filter_map(|id| {
+                let words = deck_words.remove(&id).unwrap_or_default();
+                let deck = decks.get(&id);
+                let name = deck.map(|d| d.name.as_str()).unwrap_or(DEFAULT_DECK_NAME);
+                let keep_empty = id != DEFAULT_DECK_ID
+                    && deck.is_some_and(|d| !d.is_dynamic())
+                    && !Self::has_children(name, &decks);
+                (!words.is_empty() || keep_empty)
+                    .then(|| ImportChapter::with_words(Self::chapter_name(name), words))
+            })
+            .collect();
+
+        if chapters.is_empty() {
+            return Err(ImportError::InvalidFormat(
+                "Anki package contains no decks or notes".to_string(),
+            ));
+        }
+        Ok(chapters)
+    }
+
+    fn extract_collection(data: &[u8]) -> Result<Vec<u8>, ImportError> {
+        let mut archive = ZipArchive::new(Cursor::new(data))?;
+
+        if archive.by_name(COLLECTION_FILE_V21).is_err() && archive.by_name(COLLECTION_FILE_V21B).is_ok() {
+            return Err(ImportError::InvalidFormat(
+                "Anki packages in the compressed 2.1.50+ format are not supported; \
+                 export with \"Support older Anki versions\" enabled"
+                    .to_string(),
+            ));
+        }
+
+        for name in [COLLECTION_FILE_V21, COLLECTION_FILE] {
+            if let Ok(mut file) = archive.by_name(name) {
+                let mut content = Vec::new();
+                file.read_to_end(&mut content)?;
+                return Ok(content);
+            }
+        }
+
+        Err(ImportError::MissingField(COLLECTION_FILE.to_string()))
+    }
+
+    async fn write(
+        root: Option<&str>,
+        description: Option<&str>,
+        chapters: &[ImportChapter],
+    ) -> Result<Vec<u8>, ImportError> {
+        let now = Utc::now();
+        let now_ms = now.timestamp_millis();
+        let now_secs = now.timestamp();
+        let model_id = now_ms;
+
+        let mut decks = Map::new();
+        decks.insert(
+            DEFAULT_DECK_ID.to_string(),
+            Self::deck_json(DEFAULT_DECK_ID, DEFAULT_DECK_NAME, "", now_secs),
+        );
+        if let Some(root) = root {
+            decks.insert(
+                now_ms.to_string(),
+                Self::deck_json(now_ms, &Self::deck_name(root), description.unwrap_or_default(), now_secs),
+            );
+        }
+
+        let mut deck_ids = Vec::with_capacity(chapters.len());
+        for (idx, chapter) in chapters.iter().enumerate() {
+            let id = now_ms + 1 + idx as i64;
+            let name = match root {
+                Some(root) => format!(
+                    "{}{}{}",
+                    Self::deck_name(root),
+                    DECK_SEPARATOR,
+                    Self::deck_name(&chapter.name)
+                ),
+                None => Self::deck_name(&chapter.name),
+            };
+            decks.insert(id.to_string(), Self::deck_json(id, &name, "", now_secs));
+            deck_ids.push(id);
+        }
+
+        let collection = TempDatabase::new(COLLECTION_EXTENSION);
+        let mut conn = collection.connect(true).await.map_err(Self::collection_error)?;
+
+        let mut tx = conn.begin().await.map_err(Self::collection_error)?;
+        for statement in SCHEMA.split(';').map(str::trim).filter(|s| !s.is_empty()) {
+            sqlx::query(statement).execute(&mut *tx).await.map_err(Self::collection_error)?;
+        }
+
+        let mut position: i64 = 0;
+        for (chapter, deck_id) in chapters.iter().zip(&deck_ids) {
+            for word in &chapter.words {
+                let id = now_ms + position;
+                sqlx::query("INSERT INTO notes VALUES (?, ?, ?, ?, -1, ?, ?, ?, ?, 0, '')")
+                    .bind(id)
+                    .bind(Uuid::new_v4().simple().to_string())
+                    .bind(model_id)
+                    .bind(now_secs)
+                    .bind(Self::note_tags(&word.tags))
+                    .bind(Self::note_fields(word).join(FIELD_SEPARATOR))
+                    .bind(&word.source)
+                    .bind(Self::checksum(&word.source))
+                    .execute(&mut *tx)
+                    .await.map_err(Self::collection_error)?;
+                sqlx::query(
+                    "INSERT INTO cards VALUES (?, ?, ?, 0, ?, -1, 0, 0, ?, 0, 0, 0, 0, 0, 0, 0, 0, '')",
+                )
+                .bind(id)
+                .bind(id)
+                .bind(deck_id)
+                .bind(now_secs)
+                .bind(position)
+                .execute(&mut *tx)
+                .await.map_err(Self::collection_error)?;
+                position += 1;
+            }
+        }
+
+        let current_deck = deck_ids.first().copied().unwrap_or(DEFAULT_DECK_ID);
+        sqlx::query("INSERT INTO col VALUES (1, ?, ?, ?, ?, 0, 0, 0, ?, ?, ?, ?, '{}')")
+            .bind(now_secs)
+            .bind(now_ms)
+            .bind(now_ms)
+            .bind(COLLECTION_VERSION)
+            .bind(Self::conf_json(model_id, current_deck, position).to_string())
+            .bind(Self::models_json(model_id, current_deck, now_secs).to_string())
+            .bind(Value::Object(decks).to_string())
+            .bind(Self::dconf_json().to_string())
+            .execute(&mut *tx)
+            .await.map_err(Self::collection_error)?;
+        tx.commit().await.map_err(Self::collection_error)?;
+        conn.close().await.map_err(Self::collection_error)?;
+
+        let collection_data = tokio::fs::read(&collection.path).await?;
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        writer.start_file(COLLECTION_FILE, options)?;
+        writer.write_all(&collection_data)?;
+        writer.start_file(MEDIA_FILE, options)?;
+        writer.write_all(b"{}")?;
+        Ok(writer.finish()?.into_inner())
+    }
+
+    fn note_fields(word: &ImportWord) -> [String; 6] {
+        [
+            Markup::escape(&word.source),
+            Markup::escape(&word.translation),
+            Markup::escape(word.note.as_deref().unwrap_or_default()),
+            Markup::escape(word.phonetic.as_deref().unwrap_or_default()),
+            Markup::escape(word.part_of_speech.as_deref().unwrap_or_default()),
+            Markup::escape(&ImportExample::join(&word.examples)),
+        ]
+    }
+
+    fn note_tags(tags: &[ImportTag]) -> String {
+        let tags: Vec<String> = tags
+            .iter()
+            .map(|tag| tag.name.trim().replace(char::is_whitespace, "_"))
+            .filter(|tag| !tag.is_empty())
+            .collect();
+        if tags.is_empty() {
+            String::new()
+        } else {
+            format!(" {} ", tags.join(" "))
+        }
+    }
+
+    fn checksum(text: &str) -> i64 {
+        let hash = Sha1::digest(text.as_bytes());
+        u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]]) as i64
+    }
+
+    fn deck_name(name: &str) -> String {
+        name.replace(DECK_SEPARATOR, ":")
+    }
+
+    fn chapter_name(deck_name: &str) -> String {
+        deck_name
+            .rsplit(DECK_SEPARATOR)
+            .next()
+            .unwrap_or(deck_name)
+            .trim()
+            .to_string()
+    }
+
+    fn has_children(name: &str, decks: &HashMap<i64, AnkiDeck>) -> bool {
+        let prefix = format!("{}{}", name, DECK_SEPARATOR);
+        decks.values().any(|deck| deck.name.starts_with(&prefix))
+    }
+
+    fn deck_json(id: i64, name: &str, description: &str, modified: i64) -> Value {
+        json!({
+            "id": id,
+            "name": name,
+            "desc": description,
+            "mod": modified,
+            "usn": -1,
+            "dyn": 0,
+            "conf": 1,
+            "collapsed": false,
+            "browserCollapsed": false,
+            "extendNew": 10,
+            "extendRev": 50,
+            "newToday": [0, 0],
+            "revToday": [0, 0],
+            "lrnToday": [0, 0],
+            "timeToday": [0, 0]
+        })
+    }
+
+    fn models_json(model_id: i64, deck_id: i64, modified: i64) -> Value {
+        let fields: Vec<Value> = FIELD_NAMES
+            .iter()
+            .enumerate()
+            .map(|(ord, name)| {
+                json!({
+                    "name": name,
+                    "ord": ord,
+                    "sticky": false,
+                    "rtl": false,
+                    "font": "Arial",
+                    "size": 20,
+                    "media": []
+                })
+            })
+            .collect();
+
+        let mut models = Map::new();
+        models.insert(
+            model_id.to_string(),
+            json!({
+                "id": model_id,
+                "name": MODEL_NAME,
+                "type": 0,
+                "mod": modified,
+                "usn": -1,
+                "sortf": 0,
+                "did": deck_id,
+                "tmpls": [{
+                    "name": "Card 1",
+                    "ord": 0,
+                    "qfmt": QUESTION_TEMPLATE,
+                    "afmt": ANSWER_TEMPLATE,
+                    "bqfmt": "",
+                    "bafmt": "",
+                    "did": null,
+                    "bfont": "",
+                    "bsize": 0
+                }],
+                "flds": fields,
+                "css": CARD_CSS,
+                "latexPre": LATEX_PRE,
+                "latexPost": LATEX_POST,
+                "latexsvg": false,
+                "req": [[0, "any", [0]]],
+                "tags": [],
+                "vers": []
+            }),
+        );
+        Value::Object(models)
+    }
+
+    fn conf_json(model_id: i64, deck_id: i64, next_position: i64) -> Value {
+        json!({
+            "activeDecks": [deck_id],
+            "curDeck": deck_id,
+            "curModel": model_id.to_string(),
+            "nextPos": next_position,
+            "newSpread": 0,
+            "collapseTime": 1200,
+            "timeLim": 0,
+            "estTimes": true,
+            "dueCounts": true,
+            "sortType": "noteFld",
+            "sortBackwards": false,
+            "addToCur": true
+        })
+    }
+
+    fn dconf_json() -> Value {
+        json!({
+            "1": {
+                "id": 1,
+                "name": DEFAULT_DECK_NAME,
+                "mod": 0,
+                "usn": 0,
+                "dyn": false,
+                "maxTaken": 60,
+                "autoplay": true,
+                "replayq": true,
+                "timer": 0,
+                "new": {
+                    "bury": true,
+                    "delays": [1.0, 10.0],
+                    "initialFactor": 2500,
+                    "ints": [1, 4, 7],
+                    "order": 1,
+                    "perDay": 20,
+                    "separate": true
+                },
+                "rev": {
+                    "bury": true,
+                    "ease4": 1.3,
+                    "fuzz": 0.05,
+                    "ivlFct": 1.0,
+                    "maxIvl": 36500,
+                    "minSpace": 1,
+                    "perDay": 200
+                },
+                "lapse": {
+                    "delays": [10.0],
+                    "leechAction": 0,
+                    "leechFails": 8,
+                    "minInt": 1,
+                    "mult": 0.0
+                }
+            }
+        })
+    }
+
+    fn collection_error(e: sqlx::Error) -> ImportError {
+        ImportError::AnkiError(e.to_string())
+    }
+}
+
+impl AnkiDeck {
+    fn is_dynamic(&self) -> bool {
+        self.dynamic.as_bool().unwrap_or(false) || self.dynamic.as_i64().unwrap_or(0) != 0
+    }
+}
+
+impl NoteFields {
+    fn positional() -> Self {
+        Self {
+            source: 0,
+            translation: Some(1),
+            note: None,
+            phonetic: None,
+            part_of_speech: None,
+            examples: None,
+        }
+    }
+
+    fn from_model(model: &AnkiModel) -> Self {
+        let find = |aliases: &[&str]| {
+            model
+                .flds
+                .iter()
+                .find(|field| aliases.contains(&Self::normalize(&field.name).as_str()))
+                .map(|field| field.ord)
+        };
+
+        let source = find(SOURCE_ALIASES).unwrap_or(0);
+        let translation = find(TRANSLATION_ALIASES).or_else(|| {
+            model
+                .flds
+                .iter()
+                .map(|field| field.ord)
+                .filter(|ord| *ord != source)
+                .min()
+        });
+
+        Self {
+            source,
+            translation,
+            note: find(NOTE_ALIASES),
+            phonetic: find(PHONETIC_ALIASES),
+            part_of_speech: find(PART_OF_SPEECH_ALIASES),
+            examples: find(EXAMPLES_ALIASES),
+        }
+    }
+
+    fn normalize(name: &str) -> String {
+        name.chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect()
+    }
+
+    fn read(&self, flds: &str, tags: &str) -> Option<ImportWord> {
+        let values: Vec<&str> = flds.split(FIELD_SEPARATOR).collect();
+        let value = |idx: Option<usize>| {
+            idx.and_then(|idx| values.get(idx))
+                .map(|value| Markup::strip(value).trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+
+        let source = value(Some(self.source))?;
+        let examples = value(self.examples)
+            .map(|examples| ImportExample::split(&examples))
+            .unwrap_or_default();
+
+        Some(
+            ImportWord::new(source, value(self.translation).unwrap_or_default(), value(self.note))
+                .with_details(value(self.phonetic), value(self.part_of_speech), examples)
+                .with_tags(
+                    tags.split_whitespace()
+                        .map(|tag| ImportTag::named(tag.to_string()))
+                        .collect(),
+                ),
+        )
+    }
+}
\ No newline at end of file
@@ -0,0 +1,34 @@
+use std::collections::HashMap;
+
+use crate::import::data::ImportChapter;
+use crate::import::data::ImportWord;
+
+#[derive(Default)]
+pub struct ChapterCollector {
+    chapters: Vec<(ImportChapter, Option<i32>)>,
+    positions: HashMap<String, usize>,
+}
+
+impl ChapterCollector {
+    pub fn push(&mut self, name: String, order: Option<i32>, word: ImportWord) {
+        let idx = match self.positions.get(&name) {
+            Some(idx) => *idx,
+            None => {
+                self.positions.insert(name.clone(), self.chapters.len());
+                self.chapters.push((ImportChapter::with_words(name, Vec::new()), None));
+                self.chapters.len() - 1
+            }
+        };
+
+        let (chapter, chapter_order) = &mut self.chapters[idx];
+        if chapter_order.is_none() {
+            *chapter_order = order;
+        }
+        chapter.words.push(word);
+    }
+
+    pub fn into_chapters(mut self) -> Vec<ImportChapter> {
+        self.chapters.sort_by_key(|(_, order)| (order.is_none(), *order));
+        self.chapters.into_iter().map(|(chapter, _)| chapter).collect()
+    }
+}
\ No newline at end of file
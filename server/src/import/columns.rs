@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::import::data::ImportExample;
+use crate::import::data::ImportTag;
+use crate::import::data::ImportWord;
+use crate::import::error::ImportError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ColumnField {
+    ChapterName,
+    ChapterOrder,
+    Source,
+    Translation,
+    Note,
+    Phonetic,
+    PartOfSpeech,
+    Examples,
+    Tags,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum ColumnRef {
+    Position(usize),
+    Header(String),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ColumnMap {
+    columns: HashMap<ColumnField, ColumnRef>,
+}
+
+#[derive(Default)]
+pub struct ColumnIndices {
+    pub chapter_name: Option<usize>,
+    pub chapter_order: Option<usize>,
+    pub source: usize,
+    pub translation: Option<usize>,
+    pub note: Option<usize>,
+    pub phonetic: Option<usize>,
+    pub part_of_speech: Option<usize>,
+    pub examples: Option<usize>,
+    pub tags: Option<usize>,
+}
+
+impl ColumnField {
+    const ALL: [ColumnField; 9] = [
+        Self::ChapterName,
+        Self::ChapterOrder,
+        Self::Source,
+        Self::Translation,
+        Self::Note,
+        Self::Phonetic,
+        Self::PartOfSpeech,
+        Self::Examples,
+        Self::Tags,
+    ];
+
+    pub fn name(self) -> &'static str {
+        self.aliases()[0]
+    }
+
+    // The first alias is the canonical header written by templates and exports.
+    fn aliases(self) -> &'static [&'static str] {
+        match self {
+            Self::ChapterName => &[
+                "chapter_name", "chapter", "unit", "lesson", "section",
+                "章节", "章节名", "章节名称", "单元", "课", "课程",
+            ],
+            Self::ChapterOrder => &["chapter_order", "sort_order", "章节顺序", "单元顺序"],
+            Self::Source => &[
+                "source", "word", "words", "term", "headword", "vocabulary", "english", "front",
+                "单词", "词汇", "生词", "词语", "英文", "原文",
+            ],
+            Self::Translation => &[
+                "translation", "meaning", "definition", "chinese", "back",
+                "释义", "翻译", "中文", "意思", "词义", "解释", "中文释义",
+            ],
+            Self::Note => &["note", "notes", "remark", "comment", "备注", "笔记", "注释"],
+            Self::Phonetic => &["phonetic", "phonetics", "pronunciation", "ipa", "音标", "发音"],
+            Self::PartOfSpeech => &["part_of_speech", "pos", "word class", "词性"],
+            Self::Examples => &["examples", "example", "sentence", "sentences", "例句", "例子"],
+            Self::Tags => &["tags", "tag", "labels", "标签", "分类"],
+        }
+    }
+
+    fn from_header(header: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|field| {
+            field
+                .aliases()
+                .iter()
+                .any(|alias| ColumnIndices::normalize(alias) == header)
+        })
+    }
+}
+
+impl ColumnMap {
+    pub fn parse(value: &str) -> Result<Self, ImportError> {
+        if value.trim().is_empty() {
+            return Ok(Self::default());
+        }
+        let columns = serde_json::from_str(value)
+            .map_err(|e| ImportError::InvalidFormat(format!("Invalid column_map: {}", e)))?;
+        Ok(Self { columns })
+    }
+
+    fn resolve(&self, header: &[Option<String>]) -> Result<HashMap<ColumnField, usize>, ImportError> {
+        let mut resolved = HashMap::new();
+        for (field, column) in &self.columns {
+            let idx = match column {
+                ColumnRef::Position(position) if *position >= 1 => position - 1,
+                ColumnRef::Position(_) => {
+                    return Err(ImportError::InvalidFormat(format!(
+                        "Invalid column_map: column numbers for {} start at 1",
+                        field.name()
+                    )))
+                }
+                ColumnRef::Header(name) => {
+                    let name = ColumnIndices::normalize(name);
+                    header
+                        .iter()
+                        .position(|cell| cell.as_deref() == Some(name.as_str()))
+                        .ok_or_else(|| {
+                            ImportError::InvalidFormat(format!(
+                                "Invalid column_map: no '{}' column for {}",
+                                name,
+                                field.name()
+                            ))
+                        })?
+                }
+            };
+            resolved.insert(*field, idx);
+        }
+        Ok(resolved)
+    }
+}
+
+impl ColumnIndices {
+    pub fn from_header<I, S>(
+        header: I,
+        require_chapter: bool,
+        column_map: &ColumnMap,
+    ) -> Result<Self, ImportError>
+    where
+        I: IntoIterator<Item = Option<S>>,
+        S: AsRef<str>,
+    {
+        let header: Vec<Option<String>> = header
+            .into_iter()
+            .map(|cell| cell.map(|name| Self::normalize(name.as_ref())))
+            .collect();
+
+        let mut columns = HashMap::new();
+        for (idx, cell) in header.iter().enumerate() {
+            if let Some(field) = cell.as_deref().and_then(ColumnField::from_header) {
+                columns.entry(field).or_insert(idx);
+            }
+        }
+        columns.extend(column_map.resolve(&header)?);
+
+        let required = |field: ColumnField| {
+            columns.get(&field).copied().ok_or_else(|| ImportError::MissingColumn {
+                field: field.name(),
+                aliases: field.aliases().join(", "),
+            })
+        };
+        let source = required(ColumnField::Source)?;
+        let chapter_name = match require_chapter {
+            true => Some(required(ColumnField::ChapterName)?),
+            false => columns.get(&ColumnField::ChapterName).copied(),
+        };
+
+        Ok(Self {
+            chapter_name,
+            chapter_order: columns.get(&ColumnField::ChapterOrder).copied(),
+            source,
+            translation: columns.get(&ColumnField::Translation).copied(),
+            note: columns.get(&ColumnField::Note).copied(),
+            phonetic: columns.get(&ColumnField::Phonetic).copied(),
+            part_of_speech: columns.get(&ColumnField::PartOfSpeech).copied(),
+            examples: columns.get(&ColumnField::Examples).copied(),
+            tags: columns.get(&ColumnField::Tags).copied(),
+        })
+    }
+
+    fn normalize(name: &str) -> String {
+        name.to_lowercase()
+            .replace(['_', '-'], " ")
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    pub fn read_chapter_name(&self, cell: impl Fn(usize) -> Option<String>) -> String {
+        self.chapter_name.and_then(cell).unwrap_or_default()
+    }
+
+    pub fn read_chapter_order(
+        &self,
+        row: usize,
+        cell: impl Fn(usize) -> Option<String>,
+    ) -> Result<Option<i32>, ImportError> {
+        self.chapter_order
+            .and_then(cell)
+            .map(|value| {
+                value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|order| order.fract() == 0.0 && order.abs() <= i32::MAX as f64)
+                    .map(|order| order as i32)
+                    .ok_or_else(|| {
+                        ImportError::invalid_data(row, format!("Invalid chapter_order '{}'", value))
+                    })
+            })
+            .transpose()
+    }
+
+    pub fn read_word(
+        &self,
+        row: usize,
+        cell: impl Fn(usize) -> Option<String>,
+    ) -> ImportWord {
+        let field = |idx: Option<usize>| idx.and_then(&cell);
+
+        let examples = field(self.examples)
+            .map(|value| ImportExample::split(&value))
+            .unwrap_or_default();
+        let tags = field(self.tags)
+            .map(|value| ImportTag::split(&value))
+            .unwrap_or_default();
+
+        ImportWord::new(
+            cell(self.source).unwrap_or_default(),
+            field(self.translation).unwrap_or_default(),
+            field(self.note),
+        )
+        .with_details(field(self.phonetic), field(self.part_of_speech), examples)
+        .with_tags(tags)
+        .at_row(row)
+    }
+}
\ No newline at end of file
@@ -1,12 +1,54 @@
 use serde::Deserialize;
 use serde::Serialize;
 
+const EXAMPLE_SEPARATOR: &str = "||";
+const EXAMPLE_TRANSLATION_SEPARATOR: &str = "|";
+const TAG_SEPARATOR: char = ';';
+const TAG_COLOR_PREFIX: char = '#';
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportExample {
+    pub sentence: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translation: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "TagValue", into = "TagValue")]
+pub struct ImportTag {
+    pub name: String,
+    pub color: Option<String>,
+}
+
+// Tags are written as plain names unless they carry a color.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum TagValue {
+    Name(String),
+    Full {
+        name: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        color: Option<String>,
+    },
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImportWord {
     pub source: String,
+    #[serde(default)]
     pub translation: String,
     #[serde(default)]
     pub note: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phonetic: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_of_speech: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<ImportExample>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<ImportTag>,
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub row: Option<usize>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -45,9 +87,132 @@ pub struct XmlChapter {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct XmlWord {
     pub source: String,
+    #[serde(default)]
     pub translation: String,
     #[serde(default)]
     pub note: Option<String>,
+    #[serde(default)]
+    pub phonetic: Option<String>,
+    #[serde(default)]
+    pub part_of_speech: Option<String>,
+    #[serde(default, rename = "example")]
+    pub examples: Vec<ImportExample>,
+    #[serde(default, rename = "tag")]
+    pub tags: Vec<XmlTag>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct XmlTag {
+    #[serde(default, rename = "@color", skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    #[serde(rename = "$text")]
+    pub name: String,
+}
+
+impl ImportExample {
+    pub fn join(examples: &[ImportExample]) -> String {
+        examples
+            .iter()
+            .map(|example| match &example.translation {
+                Some(translation) => format!(
+                    "{} {} {}",
+                    example.sentence, EXAMPLE_TRANSLATION_SEPARATOR, translation
+                ),
+                None => example.sentence.clone(),
+            })
+            .collect::<Vec<_>>()
+            .join(&format!(" {} ", EXAMPLE_SEPARATOR))
+    }
+
+    pub fn split(cell: &str) -> Vec<ImportExample> {
+        cell.split(EXAMPLE_SEPARATOR)
+            .filter_map(|part| {
+                let (sentence, translation) = match part.split_once(EXAMPLE_TRANSLATION_SEPARATOR) {
+                    Some((sentence, translation)) => (sentence, Some(translation.trim())),
+                    None => (part, None),
+                };
+                let sentence = sentence.trim();
+                (!sentence.is_empty()).then(|| ImportExample {
+                    sentence: sentence.to_string(),
+                    translation: translation.filter(|t| !t.is_empty()).map(String::from),
+                })
+            })
+            .collect()
+    }
+}
+
+impl ImportTag {
+    pub fn named(name: String) -> Self {
+        Self { name, color: None }
+    }
+
+    pub fn is_color(value: &str) -> bool {
+        value
+            .strip_prefix(TAG_COLOR_PREFIX)
+            .is_some_and(|hex| {
+                matches!(hex.len(), 3 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit())
+            })
+    }
+
+    pub fn join(tags: &[ImportTag]) -> String {
+        tags.iter()
+            .map(|tag| match &tag.color {
+                Some(color) => format!("{}{}", tag.name, color),
+                None => tag.name.clone(),
+            })
+            .collect::<Vec<_>>()
+            .join(&format!("{} ", TAG_SEPARATOR))
+    }
+
+    pub fn split(cell: &str) -> Vec<ImportTag> {
+        cell.split(TAG_SEPARATOR)
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .map(|part| match part.rfind(TAG_COLOR_PREFIX) {
+                Some(idx) if idx > 0 && Self::is_color(&part[idx..]) => Self {
+                    name: part[..idx].trim().to_string(),
+                    color: Some(part[idx..].to_lowercase()),
+                },
+                _ => Self::named(part.to_string()),
+            })
+            .collect()
+    }
+}
+
+impl From<TagValue> for ImportTag {
+    fn from(value: TagValue) -> Self {
+        match value {
+            TagValue::Name(name) => Self::named(name),
+            TagValue::Full { name, color } => Self { name, color },
+        }
+    }
+}
+
+impl From<ImportTag> for TagValue {
+    fn from(tag: ImportTag) -> Self {
+        match tag.color {
+            Some(color) => Self::Full { name: tag.name, color: Some(color) },
+            None => Self::Name(tag.name),
+        }
+    }
+}
+
+impl From<XmlTag> for ImportTag {
+    fn from(xml: XmlTag) -> Self {
+        Self {
+            name: xml.name,
+            color: xml.color,
+        }
+    }
+}
+
+impl From<&ImportTag> for XmlTag {
+    fn from(tag: &ImportTag) -> Self {
+        Self {
+            color: tag.color.clone(),
+            name: tag.name.clone(),
+        }
+    }
 }
 
 impl ImportWord {
@@ -56,8 +221,39 @@ impl ImportWord {
             source,
             translation,
             note,
+            phonetic: None,
+            part_of_speech: None,
+            examples: Vec::new(),
+            tags: Vec::new(),
+            row: None,
         }
     }
+
+    pub fn with_details(
+        mut self,
+        phonetic: Option<String>,
+        part_of_speech: Option<String>,
+        examples: Vec<ImportExample>,
+    ) -> Self {
+        self.phonetic = phonetic;
+        self.part_of_speech = part_of_speech;
+        self.examples = examples;
+        self
+    }
+
+    pub fn with_tags(mut self, tags: Vec<ImportTag>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    pub fn normalize_source(source: &str) -> String {
+        source.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+    }
+
+    pub fn at_row(mut self, row: usize) -> Self {
+        self.row = Some(row);
+        self
+    }
 }
 
 impl ImportChapter {
@@ -91,6 +287,11 @@ impl From<XmlWord> for ImportWord {
             source: xml.source,
             translation: xml.translation,
             note: xml.note,
+            phonetic: xml.phonetic,
+            part_of_speech: xml.part_of_speech,
+            examples: xml.examples,
+            tags: xml.tags.into_iter().map(ImportTag::from).collect(),
+            row: None,
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,111 @@
+use std::borrow::Cow;
+
+use encoding_rs::Encoding;
+use encoding_rs::BIG5;
+use encoding_rs::GB18030;
+use encoding_rs::SHIFT_JIS;
+use encoding_rs::UTF_16BE;
+use encoding_rs::UTF_16LE;
+use encoding_rs::UTF_8;
+
+use crate::import::error::ImportError;
+
+// Tried in order when a file has no BOM; the first one that decodes without errors wins.
+const FALLBACK_ENCODINGS: [&Encoding; 4] = [UTF_8, GB18030, BIG5, SHIFT_JIS];
+const BIG5_FIRST_ENCODINGS: [&Encoding; 4] = [UTF_8, BIG5, GB18030, SHIFT_JIS];
+const SHIFT_JIS_FIRST_ENCODINGS: [&Encoding; 4] = [UTF_8, SHIFT_JIS, GB18030, BIG5];
+const UTF16_SAMPLE_BYTES: usize = 4096;
+
+pub struct TextDecoder;
+
+impl TextDecoder {
+    pub fn for_label(label: &str) -> Result<Option<&'static Encoding>, ImportError> {
+        let label = label.trim();
+        if label.is_empty() {
+            return Ok(None);
+        }
+        Encoding::for_label(label.as_bytes())
+            .map(Some)
+            .ok_or_else(|| ImportError::EncodingError(format!("Unsupported encoding: {}", label)))
+    }
+
+    pub fn decode<'a>(
+        data: &'a [u8],
+        encoding: Option<&'static Encoding>,
+    ) -> Result<Cow<'a, str>, ImportError> {
+        if let Some((bom_encoding, bom_len)) = Encoding::for_bom(data) {
+            return Self::decode_with(bom_encoding, &data[bom_len..]);
+        }
+        if let Some(encoding) = encoding.or_else(|| Self::detect_utf16(data)) {
+            return Self::decode_with(encoding, data);
+        }
+
+        Self::candidates(data)
+            .iter()
+            .find_map(|encoding| encoding.decode_without_bom_handling_and_without_replacement(data))
+            .ok_or_else(|| {
+                ImportError::EncodingError(
+                    "Could not detect the text encoding; set the encoding field (e.g. gbk, big5, shift_jis)"
+                        .to_string(),
+                )
+            })
+    }
+
+    fn decode_with<'a>(
+        encoding: &'static Encoding,
+        data: &'a [u8],
+    ) -> Result<Cow<'a, str>, ImportError> {
+        encoding
+            .decode_without_bom_handling_and_without_replacement(data)
+            .ok_or_else(|| {
+                ImportError::EncodingError(format!("File is not valid {} text", encoding.name()))
+            })
+    }
+
+    // Most Big5 and Shift-JIS text also decodes as GB18030. Common GB2312 characters never
+    // use trail bytes below 0xA1 (frequent in Big5) or lead bytes 0x82-0x83 (Shift-JIS kana).
+    fn candidates(data: &[u8]) -> [&'static Encoding; 4] {
+        let (mut pairs, mut low_trails, mut kana_leads) = (0, 0, 0);
+        let mut idx = 0;
+        while idx + 1 < data.len() {
+            let (lead, trail) = (data[idx], data[idx + 1]);
+            if !(0x81..=0xFE).contains(&lead) {
+                idx += 1;
+                continue;
+            }
+            pairs += 1;
+            low_trails += usize::from((0x40..=0x7E).contains(&trail));
+            kana_leads += usize::from((0x82..=0x83).contains(&lead));
+            idx += 2;
+        }
+
+        if kana_leads * 5 > pairs {
+            SHIFT_JIS_FIRST_ENCODINGS
+        } else if low_trails * 5 > pairs {
+            BIG5_FIRST_ENCODINGS
+        } else {
+            FALLBACK_ENCODINGS
+        }
+    }
+
+    // UTF-16 without a BOM: mostly-ASCII text has a zero in every other byte.
+    fn detect_utf16(data: &[u8]) -> Option<&'static Encoding> {
+        let sample = &data[..data.len().min(UTF16_SAMPLE_BYTES) & !1];
+        if sample.is_empty() {
+            return None;
+        }
+
+        let units = sample.len() / 2;
+        let (even_zeros, odd_zeros) = sample.chunks_exact(2).fold((0, 0), |(even, odd), unit| {
+            (even + usize::from(unit[0] == 0), odd + usize::from(unit[1] == 0))
+        });
+
+        if odd_zeros * 2 > units && even_zeros * 20 < units {
+            Some(UTF_16LE)
+        } else if even_zeros * 2 > units && odd_zeros * 20 < units {
+            Some(UTF_16BE)
+        } else {
+            None
+        }
+    }
+}
\ No newline at end of file
@@ -11,15 +11,33 @@ pub enum ImportError {
     #[error("Missing required field: {0}")]
     MissingField(String),
 
+    #[error("Missing {field} column: use a header such as {aliases} or map it with column_map")]
+    MissingColumn { field: &'static str, aliases: String },
+
     #[error("Invalid data at row {row}: {message}")]
     InvalidData { row: usize, message: String },
 
+    #[error("Encoding error: {0}")]
+    EncodingError(String),
+
     #[error("Excel error: {0}")]
     ExcelError(String),
 
     #[error("XML error: {0}")]
     XmlError(String),
 
+    #[error("Anki package error: {0}")]
+    AnkiError(String),
+
+    #[error("Kindle vocabulary error: {0}")]
+    KindleError(String),
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+
+    #[error("Archive error: {0}")]
+    ArchiveError(String),
+
     #[error("IO error: {0}")]
     IoError(String),
 }
@@ -51,6 +69,24 @@ impl From<quick_xml::DeError> for ImportError {
     }
 }
 
+impl From<csv::Error> for ImportError {
+    fn from(e: csv::Error) -> Self {
+        Self::ParseError(e.to_string())
+    }
+}
+
+impl From<sqlx::Error> for ImportError {
+    fn from(e: sqlx::Error) -> Self {
+        Self::DatabaseError(e.to_string())
+    }
+}
+
+impl From<zip::result::ZipError> for ImportError {
+    fn from(e: zip::result::ZipError) -> Self {
+        Self::ArchiveError(e.to_string())
+    }
+}
+
 impl From<serde_json::Error> for ImportError {
     fn from(e: serde_json::Error) -> Self {
         Self::ParseError(e.to_string())
@@ -0,0 +1,161 @@
+use crate::import::data::ImportChapter;
+use crate::import::data::ImportWord;
+use crate::import::data::ImportWordbook;
+use crate::import::error::ImportError;
+
+const BOM: &str = "\u{feff}";
+const DEFAULT_TERM_SEPARATOR: &str = "\t";
+const DEFAULT_CARD_SEPARATOR: &str = "\n";
+
+// Term and card separators as chosen in Quizlet, Memrise or Brainscape exports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlashcardSeparators {
+    pub term: String,
+    pub card: String,
+}
+
+pub struct FlashcardText;
+
+impl FlashcardSeparators {
+    // Missing separators fall back to Quizlet's defaults: tab between term and
+    // definition, a new line between cards.
+    pub fn from_labels(term: Option<&str>, card: Option<&str>) -> Result<Self, ImportError> {
+        let term = match term {
+            Some(label) => Self::parse(label)?,
+            None => DEFAULT_TERM_SEPARATOR.to_string(),
+        };
+        let card = match card {
+            Some(label) => Self::parse(label)?,
+            None => DEFAULT_CARD_SEPARATOR.to_string(),
+        };
+        if term == card {
+            return Err(ImportError::InvalidFormat(
+                "Term and card separators must differ".to_string(),
+            ));
+        }
+        Ok(Self { term, card })
+    }
+
+    // Accepts the names used by flashcard apps, or a custom string where `\t`,
+    // `\n` and `\\` are escapes.
+    fn parse(label: &str) -> Result<String, ImportError> {
+        let separator = match label.trim().to_lowercase().as_str() {
+            "tab" => "\t".to_string(),
+            "comma" => ",".to_string(),
+            "semicolon" => ";".to_string(),
+            "newline" | "new_line" | "line" => "\n".to_string(),
+            "blank_line" => "\n\n".to_string(),
+            "dash" => " - ".to_string(),
+            _ => Self::unescape(label),
+        };
+        if separator.is_empty() {
+            return Err(ImportError::InvalidFormat(
+                "Flashcard separators cannot be empty".to_string(),
+            ));
+        }
+        Ok(separator)
+    }
+
+    fn unescape(label: &str) -> String {
+        let mut result = String::with_capacity(label.len());
+        let mut chars = label.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                result.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('t') => result.push('\t'),
+                Some('n') => result.push('\n'),
+                Some('r') => result.push('\r'),
+                Some(other) => result.push(other),
+                None => result.push('\\'),
+            }
+        }
+        result
+    }
+}
+
+impl FlashcardText {
+    // Flashcard sets are flat, so a wordbook import holds a single chapter.
+    pub fn parse_wordbook(
+        content: &str,
+        wordbook_name: String,
+        separators: &FlashcardSeparators,
+    ) -> Result<ImportWordbook, ImportError> {
+        let chapter = Self::parse_chapter(content, wordbook_name.clone(), separators)?;
+        Ok(ImportWordbook {
+            name: wordbook_name,
+            description: None,
+            chapters: vec![chapter],
+        })
+    }
+
+    pub fn parse_chapter(
+        content: &str,
+        chapter_name: String,
+        separators: &FlashcardSeparators,
+    ) -> Result<ImportChapter, ImportError> {
+        let content = content.strip_prefix(BOM).unwrap_or(content);
+        let content = match separators.card.contains('\r') {
+            true => content.to_string(),
+            false => content.replace("\r\n", "\n"),
+        };
+        let words: Vec<ImportWord> = content
+            .split(separators.card.as_str())
+            .map(str::trim)
+            .filter(|card| !card.is_empty())
+            .enumerate()
+            .map(|(idx, card)| Self::parse_card(card, &separators.term).at_row(idx + 1))
+            .collect();
+
+        if words.is_empty() {
+            return Err(ImportError::InvalidFormat(
+                "No cards found; check the term and card separators".to_string(),
+            ));
+        }
+        Ok(ImportChapter::with_words(chapter_name, words))
+    }
+
+    pub fn write_wordbook(data: &ImportWordbook, separators: &FlashcardSeparators) -> String {
+        let words: Vec<&ImportWord> = data.chapters.iter().flat_map(|c| &c.words).collect();
+        Self::write_cards(&words, separators)
+    }
+
+    pub fn write_chapter(data: &ImportChapter, separators: &FlashcardSeparators) -> String {
+        let words: Vec<&ImportWord> = data.words.iter().collect();
+        Self::write_cards(&words, separators)
+    }
+
+    // Only the first term separator splits a card, so definitions may contain it.
+    fn parse_card(card: &str, term_separator: &str) -> ImportWord {
+        let (term, definition) = card.split_once(term_separator).unwrap_or((card, ""));
+        ImportWord::new(term.trim().to_string(), definition.trim().to_string(), None)
+    }
+
+    // The formats have no escaping, so separators inside a field become spaces.
+    fn write_cards(words: &[&ImportWord], separators: &FlashcardSeparators) -> String {
+        let cards: Vec<String> = words
+            .iter()
+            .map(|word| {
+                let term = Self::clean(&word.source, &[&separators.term, &separators.card]);
+                let definition = Self::clean(&word.translation, &[&separators.card]);
+                format!("{}{}{}", term, separators.term, definition)
+            })
+            .collect();
+
+        let mut content = cards.join(&separators.card);
+        if separators.card.ends_with('\n') && !content.is_empty() {
+            content.push('\n');
+        }
+        content
+    }
+
+    fn clean(value: &str, separators: &[&str]) -> String {
+        let mut value = value.trim().to_string();
+        for separator in separators {
+            value = value.replace(separator, " ");
+        }
+        value
+    }
+}
\ No newline at end of file
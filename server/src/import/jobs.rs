@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use chrono::Utc;
+use sea_orm::ActiveModelTrait;
+use sea_orm::ActiveValue::NotSet;
+use sea_orm::ActiveValue::Unchanged;
+use sea_orm::ColumnTrait;
+use sea_orm::DatabaseConnection;
+use sea_orm::DbErr;
+use sea_orm::EntityTrait;
+use sea_orm::QueryFilter;
+use sea_orm::Set;
+use serde::Serialize;
+use tokio::sync::watch;
+
+use crate::error::AppError;
+use crate::import::writer::ImportResult;
+
+pub const JOB_QUEUED: &str = "queued";
+pub const JOB_RUNNING: &str = "running";
+pub const JOB_SUCCEEDED: &str = "succeeded";
+pub const JOB_FAILED: &str = "failed";
+const INTERRUPTED_ERROR: &str = "Import was interrupted by a server restart";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportJobView {
+    pub id: i32,
+    pub kind: String,
+    pub file_name: String,
+    pub wordbook_id: Option<i32>,
+    pub status: String,
+    pub total_rows: usize,
+    pub processed_rows: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<ImportResult>,
+    pub errors: Vec<String>,
+    pub created_at: String,
+    pub started_at: Option<String>,
+    pub finished_at: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct JobError(pub Vec<String>);
+
+#[derive(Clone)]
+pub struct JobReporter {
+    sender: watch::Sender<ImportJobView>,
+}
+
+// Live progress stays in memory: SQLite keeps the import transaction's write lock until
+// it commits, so the job record is only written when a job starts and finishes.
+#[derive(Clone, Default)]
+pub struct ImportJobs {
+    live: Arc<Mutex<HashMap<i32, watch::Sender<ImportJobView>>>>,
+}
+
+impl ImportJobs {
+    pub async fn create(
+        db: &DatabaseConnection,
+        user_id: i32,
+        kind: &str,
+        file_name: &str,
+        wordbook_id: Option<i32>,
+    ) -> Result<ImportJobView, DbErr> {
+        let job = entity::import_jobs::ActiveModel {
+            id: NotSet,
+            user_id: Set(user_id),
+            wordbook_id: Set(wordbook_id),
+            kind: Set(kind.to_string()),
+            file_name: Set(file_name.to_string()),
+            status: Set(JOB_QUEUED.to_string()),
+            total_rows: Set(0),
+            processed_rows: Set(0),
+            result: Set(None),
+            errors: Set(None),
+            created_at: Set(Utc::now().fixed_offset()),
+            started_at: Set(None),
+            finished_at: Set(None),
+        }
+        .insert(db)
+        .await?;
+        Ok(job.into())
+    }
+
+    pub async fn fail_interrupted(db: &DatabaseConnection) -> Result<u64, DbErr> {
+        let errors = serde_json::to_string(&[INTERRUPTED_ERROR]).unwrap_or_default();
+        let updated = entity::import_jobs::Entity::update_many()
+            .set(entity::import_jobs::ActiveModel {
+                status: Set(JOB_FAILED.to_string()),
+                errors: Set(Some(errors)),
+                finished_at: Set(Some(Utc::now().fixed_offset())),
+                ..Default::default()
+            })
+            .filter(entity::import_jobs::Column::Status.is_in([JOB_QUEUED, JOB_RUNNING]))
+            .exec(db)
+            .await?;
+        Ok(updated.rows_affected)
+    }
+
+    pub fn spawn<F, Fut>(&self, db: Arc<DatabaseConnection>, job: &ImportJobView, run: F)
+    where
+        F: FnOnce(JobReporter) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<ImportResult, JobError>> + Send + 'static,
+    {
+        let (sender, _) = watch::channel(job.clone());
+        self.live
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(job.id, sender.clone());
+
+        let jobs = self.clone();
+        let job_id = job.id;
+        tokio::spawn(async move {
+            let started_at = Utc::now().fixed_offset();
+            sender.send_modify(|view| {
+                view.status = JOB_RUNNING.to_string();
+                view.started_at = Some(started_at.to_rfc3339());
+            });
+            let running = entity::import_jobs::ActiveModel {
+                id: Unchanged(job_id),
+                status: Set(JOB_RUNNING.to_string()),
+                started_at: Set(Some(started_at)),
+                ..Default::default()
+            };
+            if let Err(e) = running.update(db.as_ref()).await {
+                tracing::error!("Failed to mark import job {} as running: {}", job_id, e);
+            }
+
+            let reporter = JobReporter {
+                sender: sender.clone(),
+            };
+            let outcome = tokio::spawn(run(reporter))
+                .await
+                .unwrap_or_else(|e| Err(JobError(vec![format!("Import task failed: {}", e)])));
+
+            let finished_at = Utc::now().fixed_offset();
+            sender.send_modify(|view| {
+                view.finished_at = Some(finished_at.to_rfc3339());
+                match outcome {
+                    Ok(result) => {
+                        view.status = JOB_SUCCEEDED.to_string();
+                        view.processed_rows = view.total_rows;
+                        view.result = Some(result);
+                    }
+                    Err(JobError(errors)) => {
+                        view.status = JOB_FAILED.to_string();
+                        view.errors = errors;
+                    }
+                }
+            });
+
+            let view = sender.borrow().clone();
+            let finished = entity::import_jobs::ActiveModel {
+                id: Unchanged(job_id),
+                status: Set(view.status.clone()),
+                total_rows: Set(view.total_rows as i32),
+                processed_rows: Set(view.processed_rows as i32),
+                result: Set(view.result.as_ref().and_then(|r| serde_json::to_string(r).ok())),
+                errors: Set((!view.errors.is_empty())
+                    .then(|| serde_json::to_string(&view.errors).ok())
+                    .flatten()),
+                finished_at: Set(Some(finished_at)),
+                ..Default::default()
+            };
+            if let Err(e) = finished.update(db.as_ref()).await {
+                tracing::error!("Failed to save import job {}: {}", job_id, e);
+            }
+
+            jobs.live
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .remove(&job_id);
+        });
+    }
+
+    pub fn subscribe(&self, job_id: i32) -> Option<watch::Receiver<ImportJobView>> {
+        self.live
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&job_id)
+            .map(watch::Sender::subscribe)
+    }
+
+    pub fn current(&self, job: entity::import_jobs::Model) -> ImportJobView {
+        match self.subscribe(job.id) {
+            Some(receiver) => receiver.borrow().clone(),
+            None => job.into(),
+        }
+    }
+}
+
+impl JobReporter {
+    pub fn start(&self, total_rows: usize) {
+        self.sender.send_modify(|view| view.total_rows = total_rows);
+    }
+
+    pub fn advance(&self, rows: usize) {
+        if rows > 0 {
+            self.sender.send_modify(|view| view.processed_rows += rows);
+        }
+    }
+}
+
+impl ImportJobView {
+    pub fn is_finished(&self) -> bool {
+        self.status == JOB_SUCCEEDED || self.status == JOB_FAILED
+    }
+}
+
+impl From<entity::import_jobs::Model> for ImportJobView {
+    fn from(job: entity::import_jobs::Model) -> Self {
+        Self {
+            id: job.id,
+            kind: job.kind,
+            file_name: job.file_name,
+            wordbook_id: job.wordbook_id,
+            status: job.status,
+            total_rows: job.total_rows.max(0) as usize,
+            processed_rows: job.processed_rows.max(0) as usize,
+            result: job.result.and_then(|r| serde_json::from_str(&r).ok()),
+            errors: job
+                .errors
+                .and_then(|e| serde_json::from_str(&e).ok())
+                .unwrap_or_default(),
+            created_at: job.created_at.to_rfc3339(),
+            started_at: job.started_at.map(|t| t.to_rfc3339()),
+            finished_at: job.finished_at.map(|t| t.to_rfc3339()),
+        }
+    }
+}
+
+impl From<AppError> for JobError {
+    fn from(e: AppError) -> Self {
+        Self(vec![e.to_string()])
+    }
+}
+
+impl From<DbErr> for JobError {
+    fn from(e: DbErr) -> Self {
+        Self(vec![e.to_string()])
+    }
+}
\ No newline at end of file
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use sqlx::Connection;
+
+use crate::import::data::ImportChapter;
+use crate::import::data::ImportExample;
+use crate::import::data::ImportWord;
+use crate::import::data::ImportWordbook;
+use crate::import::error::ImportError;
+use crate::import::temp_db::TempDatabase;
+
+const DATABASE_EXTENSION: &str = "db";
+const REQUIRED_TABLES: [&str; 3] = ["WORDS", "LOOKUPS", "BOOK_INFO"];
+const UNKNOWN_BOOK: &str = "Unknown book";
+const MAX_CHAPTER_NAME_CHARS: usize = 100;
+
+struct Lookup {
+    book: String,
+    word: String,
+    stem: String,
+    usage: String,
+}
+
+#[derive(Default)]
+struct BookWords {
+    words: Vec<ImportWord>,
+    positions: HashMap<String, usize>,
+}
+
+pub struct KindleVocab;
+
+impl KindleVocab {
+    pub async fn parse_wordbook(
+        data: &[u8],
+        wordbook_name: String,
+    ) -> Result<ImportWordbook, ImportError> {
+        let mut books: Vec<(String, BookWords)> = Vec::new();
+        for lookup in Self::read(data).await? {
+            let idx = match books.iter().position(|(book, _)| *book == lookup.book) {
+                Some(idx) => idx,
+                None => {
+                    books.push((lookup.book.clone(), BookWords::default()));
+                    books.len() - 1
+                }
+            };
+            books[idx].1.push(lookup);
+        }
+
+        Ok(ImportWordbook {
+            name: wordbook_name,
+            description: None,
+            chapters: books
+                .into_iter()
+                .map(|(book, words)| ImportChapter::with_words(Self::chapter_name(&book), words.words))
+                .collect(),
+        })
+    }
+
+    pub async fn parse_chapter(data: &[u8], chapter_name: String) -> Result<ImportChapter, ImportError> {
+        let mut words = BookWords::default();
+        for lookup in Self::read(data).await? {
+            words.push(lookup);
+        }
+        Ok(ImportChapter::with_words(chapter_name, words.words))
+    }
+
+    async fn read(data: &[u8]) -> Result<Vec<Lookup>, ImportError> {
+        let database = TempDatabase::new(DATABASE_EXTENSION);
+        tokio::fs::write(&database.path, data).await?;
+        let mut conn = database.connect(false).await.map_err(Self::not_vocab_db)?;
+
+        let (tables,): (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name IN (?, ?, ?)",
+        )
+        .bind(REQUIRED_TABLES[0])
+        .bind(REQUIRED_TABLES[1])
+        .bind(REQUIRED_TABLES[2])
+        .fetch_one(&mut conn)
+        .await
+        .map_err(Self::not_vocab_db)?;
+        if tables != REQUIRED_TABLES.len() as i64 {
+            return Err(ImportError::KindleError(
+                "Not a Kindle vocabulary database (vocab.db)".to_string(),
+            ));
+        }
+
+        let rows: Vec<(String, String, String, String)> = sqlx::query_as(
+            "SELECT COALESCE(b.title, ''), COALESCE(w.word, ''), COALESCE(w.stem, ''), \
+             COALESCE(l.usage, '') \
+             FROM LOOKUPS l JOIN WORDS w ON w.id = l.word_key \
+             LEFT JOIN BOOK_INFO b ON b.id = l.book_key \
+             ORDER BY l.timestamp, l.id",
+        )
+        .fetch_all(&mut conn)
+        .await
+        .map_err(|e| ImportError::KindleError(e.to_string()))?;
+        conn.close()
+            .await
+            .map_err(|e| ImportError::KindleError(e.to_string()))?;
+
+        if rows.is_empty() {
+            return Err(ImportError::KindleError(
+                "Kindle vocabulary database contains no lookups".to_string(),
+            ));
+        }
+
+        Ok(rows
+            .into_iter()
+            .map(|(book, word, stem, usage)| Lookup {
+                book: book.trim().to_string(),
+                word: word.trim().to_string(),
+                stem: stem.trim().to_string(),
+                usage: usage.split_whitespace().collect::<Vec<_>>().join(" "),
+            })
+            .filter(|lookup| !lookup.word.is_empty() || !lookup.stem.is_empty())
+            .collect())
+    }
+
+    fn chapter_name(book: &str) -> String {
+        if book.is_empty() {
+            return UNKNOWN_BOOK.to_string();
+        }
+        book.chars().take(MAX_CHAPTER_NAME_CHARS).collect::<String>().trim_end().to_string()
+    }
+
+    fn not_vocab_db(e: sqlx::Error) -> ImportError {
+        ImportError::KindleError(format!("Not a Kindle vocabulary database (vocab.db): {}", e))
+    }
+}
+
+impl BookWords {
+    // Repeated lookups of the same word in a book become one word with several examples.
+    fn push(&mut self, lookup: Lookup) {
+        let source = if lookup.stem.is_empty() {
+            lookup.word
+        } else {
+            lookup.stem
+        };
+        let key = ImportWord::normalize_source(&source);
+
+        let idx = match self.positions.get(&key) {
+            Some(idx) => *idx,
+            None => {
+                self.positions.insert(key, self.words.len());
+                self.words.push(ImportWord::new(source, String::new(), None));
+                self.words.len() - 1
+            }
+        };
+
+        let word = &mut self.words[idx];
+        if !lookup.usage.is_empty() && !word.examples.iter().any(|e| e.sentence == lookup.usage) {
+            word.examples.push(ImportExample {
+                sentence: lookup.usage,
+                translation: None,
+            });
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,529 @@
+use crate::import::columns::ColumnField;
+use crate::import::columns::ColumnIndices;
+use crate::import::columns::ColumnMap;
+use crate::import::data::ImportChapter;
+use crate::import::data::ImportExample;
+use crate::import::data::ImportTag;
+use crate::import::data::ImportWord;
+use crate::import::data::ImportWordbook;
+use crate::import::error::ImportError;
+
+const BOM: &str = "\u{feff}";
+const CODE_FENCE: &str = "```";
+const LINE_BREAK: &str = "<br>";
+const TRANSLATION_SEPARATOR: &str = " — ";
+// Searched left to right; the earliest match splits the source from the rest.
+const SEPARATORS: [&str; 10] = ["\t", " — ", " – ", "—", "–", "|", " - ", "：", ": ", " = "];
+const FIELD_SEPARATORS: [&str; 2] = ["\t", "|"];
+const LIST_MARKERS: [&str; 3] = ["- ", "* ", "+ "];
+const TASK_MARKERS: [&str; 3] = ["[ ] ", "[x] ", "[X] "];
+
+type ColumnValue = fn(&ImportWord) -> String;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordListStyle {
+    Markdown,
+    PlainText,
+}
+
+enum Block {
+    Heading { level: usize, text: String },
+    Word(ImportWord),
+    Text(String),
+}
+
+struct Table {
+    columns: Option<ColumnIndices>,
+    header: Option<(usize, Vec<String>)>,
+}
+
+pub struct MarkdownList;
+
+impl MarkdownList {
+    pub fn parse_wordbook(
+        content: &str,
+        wordbook_name: String,
+        style: WordListStyle,
+    ) -> Result<ImportWordbook, ImportError> {
+        let mut name = None;
+        let mut description: Vec<String> = Vec::new();
+        let mut chapters: Vec<ImportChapter> = Vec::new();
+
+        for block in Self::read(content, style) {
+            match block {
+                Block::Heading { level: 1, text } if name.is_none() && chapters.is_empty() => {
+                    name = Some(text)
+                }
+                Block::Heading { level, text } if level <= 2 => {
+                    chapters.push(ImportChapter::with_words(text, Vec::new()))
+                }
+                Block::Heading { .. } => {}
+                Block::Word(word) => match chapters.last_mut() {
+                    Some(chapter) => chapter.words.push(word),
+                    None => chapters.push(ImportChapter::with_words(
+                        name.clone().unwrap_or_else(|| wordbook_name.clone()),
+                        vec![word],
+                    )),
+                },
+                Block::Text(text) if chapters.is_empty() => description.push(text),
+                Block::Text(_) => {}
+            }
+        }
+
+        Self::check_words(chapters.iter().map(|chapter| chapter.words.len()).sum())?;
+        Ok(ImportWordbook {
+            name: name.unwrap_or(wordbook_name),
+            description: (!description.is_empty()).then(|| description.join("\n")),
+            chapters,
+        })
+    }
+
+    // The first heading of any level names the chapter; every word in the file goes into it.
+    pub fn parse_chapter(
+        content: &str,
+        chapter_name: String,
+        style: WordListStyle,
+    ) -> Result<ImportChapter, ImportError> {
+        let mut name = None;
+        let mut words = Vec::new();
+
+        for block in Self::read(content, style) {
+            match block {
+                Block::Heading { text, .. } if name.is_none() && words.is_empty() => {
+                    name = Some(text)
+                }
+                Block::Word(word) => words.push(word),
+                _ => {}
+            }
+        }
+
+        Self::check_words(words.len())?;
+        Ok(ImportChapter::with_words(name.unwrap_or(chapter_name), words))
+    }
+
+    pub fn write_wordbook(data: &ImportWordbook, style: WordListStyle) -> String {
+        let mut content = format!("# {}\n\n", Self::escape(&data.name));
+        if let Some(description) = data.description.as_deref().filter(|d| !d.trim().is_empty()) {
+            content.push_str(&format!("{}\n\n", description.trim()));
+        }
+        for chapter in &data.chapters {
+            content.push_str(&format!("## {}\n\n", Self::escape(&chapter.name)));
+            Self::write_words(&mut content, &chapter.words, style);
+            content.push('\n');
+        }
+        content
+    }
+
+    pub fn write_chapter(data: &ImportChapter, style: WordListStyle) -> String {
+        let mut content = format!("# {}\n\n", Self::escape(&data.name));
+        Self::write_words(&mut content, &data.words, style);
+        content
+    }
+
+    fn check_words(count: usize) -> Result<(), ImportError> {
+        match count {
+            0 => Err(ImportError::InvalidFormat(
+                "No words found: write each word as a '- source — translation' line or a table row"
+                    .to_string(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn read(content: &str, style: WordListStyle) -> Vec<Block> {
+        let content = content.strip_prefix(BOM).unwrap_or(content);
+        let mut blocks = Vec::new();
+        let mut table: Option<Table> = None;
+        let mut in_code = false;
+        // Plain lines under the title and before the first chapter are the description.
+        let mut titled = false;
+        let mut in_chapter = false;
+
+        for (idx, line) in content.lines().enumerate() {
+            let row = idx + 1;
+            let line = line.trim();
+
+            if line.starts_with(CODE_FENCE) {
+                in_code = !in_code;
+                continue;
+            }
+            if in_code {
+                continue;
+            }
+            if line.starts_with('|') {
+                table
+                    .get_or_insert(Table {
+                        columns: None,
+                        header: None,
+                    })
+                    .push(row, line, &mut blocks);
+                continue;
+            }
+            if let Some(table) = table.take() {
+                table.finish(&mut blocks);
+            }
+
+            if line.is_empty() || Self::is_rule(line) {
+                continue;
+            }
+            if let Some((level, text)) = Self::heading(line) {
+                titled = true;
+                in_chapter |= level > 1;
+                blocks.push(Block::Heading { level, text });
+                continue;
+            }
+
+            let item = Self::list_item(line);
+            let text = item.unwrap_or(line);
+            let word_line = match style {
+                WordListStyle::Markdown => {
+                    item.is_some() || (in_chapter || !titled) && Self::find_separator(text).is_some()
+                }
+                // Only the description sits between the title and the first chapter.
+                WordListStyle::PlainText => {
+                    in_chapter || !titled || Self::find_separator(text).is_some()
+                }
+            };
+            if word_line {
+                if let Some(word) = Self::parse_entry(text) {
+                    blocks.push(Block::Word(word.at_row(row)));
+                }
+            } else {
+                blocks.push(Block::Text(line.to_string()));
+            }
+        }
+        if let Some(table) = table {
+            table.finish(&mut blocks);
+        }
+
+        blocks
+    }
+
+    fn heading(line: &str) -> Option<(usize, String)> {
+        let level = line.chars().take_while(|c| *c == '#').count();
+        let text = &line[level..];
+        if !(1..=6).contains(&level) || !(text.is_empty() || text.starts_with(' ')) {
+            return None;
+        }
+        let text = Self::unescape(text.trim().trim_end_matches('#').trim());
+        (!text.is_empty()).then_some((level, text))
+    }
+
+    fn is_rule(line: &str) -> bool {
+        let chars: Vec<char> = line.chars().filter(|c| !c.is_whitespace()).collect();
+        chars.len() >= 3
+            && ['-', '*', '_'].iter().any(|marker| chars.iter().all(|c| c == marker))
+    }
+
+    fn list_item(line: &str) -> Option<&str> {
+        let item = LIST_MARKERS
+            .iter()
+            .find_map(|marker| line.strip_prefix(marker))
+            .or_else(|| {
+                let digits = line.chars().take_while(char::is_ascii_digit).count();
+                (digits > 0)
+                    .then(|| &line[digits..])
+                    .and_then(|rest| rest.strip_prefix(". ").or_else(|| rest.strip_prefix(") ")))
+            })?;
+        let item = item.trim_start();
+        Some(
+            TASK_MARKERS
+                .iter()
+                .find_map(|marker| item.strip_prefix(marker))
+                .unwrap_or(item),
+        )
+    }
+
+    // "source — translation (note)", or up to three tab- or pipe-separated fields.
+    fn parse_entry(text: &str) -> Option<ImportWord> {
+        let (source, translation, note) = match Self::find_separator(text) {
+            Some((_, separator)) if FIELD_SEPARATORS.contains(&separator) => {
+                let fields = Self::split_unescaped(text, separator);
+                let field = |idx: usize| fields.get(idx).map(|f| f.trim()).unwrap_or_default();
+                (field(0), field(1), Some(field(2)))
+            }
+            Some((idx, separator)) => {
+                let (translation, note) = Self::split_note(&text[idx + separator.len()..]);
+                (&text[..idx], translation, note)
+            }
+            None => (text, "", None),
+        };
+
+        let (source, phonetic) = Self::split_phonetic(Self::strip_emphasis(source.trim()));
+        let source = Self::unescape(source);
+        if source.is_empty() {
+            return None;
+        }
+        let note = note.map(Self::unescape).filter(|note| !note.is_empty());
+        Some(
+            ImportWord::new(source, Self::unescape(Self::strip_emphasis(translation.trim())), note)
+                .with_details(phonetic, None, Vec::new()),
+        )
+    }
+
+    fn find_separator(text: &str) -> Option<(usize, &'static str)> {
+        SEPARATORS
+            .iter()
+            .filter_map(|separator| {
+                text.match_indices(separator)
+                    .find(|(idx, _)| !Self::is_escaped(text, *idx))
+                    .map(|(idx, _)| (idx, *separator))
+            })
+            .min_by_key(|(idx, separator)| (*idx, usize::MAX - separator.len()))
+    }
+
+    // A trailing parenthesized group is the note, unless its closing parenthesis is escaped.
+    fn split_note(text: &str) -> (&str, Option<&str>) {
+        let text = text.trim();
+        if !text.ends_with(')') || Self::is_escaped(text, text.len() - 1) {
+            return (text, None);
+        }
+        let mut depth = 0;
+        for (idx, c) in text.char_indices().rev() {
+            match c {
+                ')' if !Self::is_escaped(text, idx) => depth += 1,
+                '(' if !Self::is_escaped(text, idx) => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return (text[..idx].trim(), Some(text[idx + 1..text.len() - 1].trim()));
+                    }
+                }
+                _ => {}
+            }
+        }
+        (text, None)
+    }
+
+    fn split_phonetic(source: &str) -> (&str, Option<String>) {
+        let Some(inner) = source.strip_suffix('/') else {
+            return (source, None);
+        };
+        match inner.rfind(" /") {
+            Some(idx) if idx + 2 < inner.len() => {
+                (inner[..idx].trim(), Some(format!("/{}/", &inner[idx + 2..])))
+            }
+            _ => (source, None),
+        }
+    }
+
+    fn strip_emphasis(text: &str) -> &str {
+        ["**", "__", "`", "*", "_"]
+            .iter()
+            .find_map(|marker| {
+                text.strip_prefix(marker)
+                    .and_then(|t| t.strip_suffix(marker))
+                    .filter(|t| !t.is_empty())
+            })
+            .unwrap_or(text)
+    }
+
+    fn split_unescaped<'t>(text: &'t str, separator: &str) -> Vec<&'t str> {
+        let mut fields = Vec::new();
+        let mut start = 0;
+        for (idx, _) in text.match_indices(separator) {
+            if !Self::is_escaped(text, idx) {
+                fields.push(&text[start..idx]);
+                start = idx + separator.len();
+            }
+        }
+        fields.push(&text[start..]);
+        fields
+    }
+
+    fn is_escaped(text: &str, idx: usize) -> bool {
+        text[..idx].chars().rev().take_while(|c| *c == '\\').count() % 2 == 1
+    }
+
+    fn unescape(text: &str) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut chars = text.chars();
+        while let Some(c) = chars.next() {
+            match (c, chars.clone().next()) {
+                ('\\', Some(next)) if !next.is_alphanumeric() && !next.is_whitespace() => {
+                    result.push(next);
+                    chars.next();
+                }
+                _ => result.push(c),
+            }
+        }
+        result
+    }
+
+    fn escape(text: &str) -> String {
+        text.replace('\\', "\\\\")
+    }
+
+    fn write_words(content: &mut String, words: &[ImportWord], style: WordListStyle) {
+        let detailed = words.iter().any(|word| {
+            word.phonetic.is_some()
+                || word.part_of_speech.is_some()
+                || !word.examples.is_empty()
+                || !word.tags.is_empty()
+        });
+        if detailed && style == WordListStyle::Markdown {
+            Self::write_table(content, words);
+            return;
+        }
+
+        let marker = match style {
+            WordListStyle::Markdown => "- ",
+            WordListStyle::PlainText => "",
+        };
+        for word in words {
+            content.push_str(marker);
+            content.push_str(&Self::escape_source(&word.source));
+            let note = word.note.as_deref().filter(|note| !note.trim().is_empty());
+            if !word.translation.is_empty() || note.is_some() {
+                content.push_str(TRANSLATION_SEPARATOR);
+                content.push_str(&Self::escape_translation(&word.translation));
+            }
+            if let Some(note) = note {
+                let gap = if word.translation.is_empty() { "" } else { " " };
+                content.push_str(&format!("{}({})", gap, Self::escape(note.trim())));
+            }
+            content.push('\n');
+        }
+    }
+
+    // Tables keep phonetics, parts of speech, examples and tags, which list lines cannot hold.
+    fn write_table(content: &mut String, words: &[ImportWord]) {
+        let columns: Vec<(ColumnField, ColumnValue)> = vec![
+            (ColumnField::Source, |w| w.source.clone()),
+            (ColumnField::Translation, |w| w.translation.clone()),
+            (ColumnField::Note, |w| w.note.clone().unwrap_or_default()),
+            (ColumnField::Phonetic, |w| w.phonetic.clone().unwrap_or_default()),
+            (ColumnField::PartOfSpeech, |w| w.part_of_speech.clone().unwrap_or_default()),
+            (ColumnField::Examples, |w| ImportExample::join(&w.examples)),
+            (ColumnField::Tags, |w| ImportTag::join(&w.tags)),
+        ];
+        let columns: Vec<_> = columns
+            .into_iter()
+            .enumerate()
+            .filter(|(idx, (_, value))| *idx < 2 || words.iter().any(|w| !value(w).is_empty()))
+            .map(|(_, column)| column)
+            .collect();
+
+        let header: Vec<&str> = columns.iter().map(|(field, _)| field.name()).collect();
+        content.push_str(&format!("| {} |\n", header.join(" | ")));
+        content.push_str(&format!("|{}\n", " --- |".repeat(columns.len())));
+        for word in words {
+            let cells: Vec<String> = columns
+                .iter()
+                .map(|(_, value)| Self::escape_cell(&value(word)))
+                .collect();
+            content.push_str(&format!("| {} |\n", cells.join(" | ")));
+        }
+    }
+
+    // Separators inside the source would otherwise split it on import.
+    fn escape_source(source: &str) -> String {
+        Self::escape(source)
+            .replace('|', "\\|")
+            .replace(':', "\\:")
+            .replace('：', "\\：")
+            .replace('—', "\\—")
+            .replace('–', "\\–")
+            .replace('=', "\\=")
+            .replace(" - ", " \\- ")
+            .replace('\t', " ")
+    }
+
+    fn escape_translation(translation: &str) -> String {
+        let translation = Self::escape(translation);
+        match translation.strip_suffix(')') {
+            Some(rest) => format!("{}\\)", rest),
+            None => translation,
+        }
+    }
+
+    fn escape_cell(value: &str) -> String {
+        Self::escape(value)
+            .replace('|', "\\|")
+            .replace("\r\n", "\n")
+            .replace('\n', LINE_BREAK)
+    }
+}
+
+impl Table {
+    fn push(&mut self, row: usize, line: &str, blocks: &mut Vec<Block>) {
+        let cells = Self::cells(line);
+        if self.columns.is_none() {
+            match self.header.take() {
+                None => {
+                    self.header = Some((row, cells));
+                    return;
+                }
+                Some((_, header)) if Self::is_delimiter(&cells) => {
+                    self.columns = Some(Self::columns(&header));
+                    return;
+                }
+                Some((header_row, header)) => {
+                    self.columns = Some(Self::positional());
+                    self.push_word(header_row, &header, blocks);
+                }
+            }
+        }
+        if !Self::is_delimiter(&cells) {
+            self.push_word(row, &cells, blocks);
+        }
+    }
+
+    fn finish(mut self, blocks: &mut Vec<Block>) {
+        if let Some((row, cells)) = self.header.take() {
+            self.columns = Some(Self::positional());
+            self.push_word(row, &cells, blocks);
+        }
+    }
+
+    fn push_word(&self, row: usize, cells: &[String], blocks: &mut Vec<Block>) {
+        let Some(columns) = &self.columns else {
+            return;
+        };
+        let cell = |idx: usize| cells.get(idx).filter(|value| !value.is_empty()).cloned();
+        let word = columns.read_word(row, cell);
+        if !word.source.is_empty() {
+            blocks.push(Block::Word(word));
+        }
+    }
+
+    // Tables without recognizable headers are read as source, translation and note.
+    fn columns(header: &[String]) -> ColumnIndices {
+        ColumnIndices::from_header(
+            header.iter().map(|cell| Some(cell.as_str())),
+            false,
+            &ColumnMap::default(),
+        )
+        .unwrap_or_else(|_| Self::positional())
+    }
+
+    fn positional() -> ColumnIndices {
+        ColumnIndices {
+            source: 0,
+            translation: Some(1),
+            note: Some(2),
+            ..Default::default()
+        }
+    }
+
+    fn cells(line: &str) -> Vec<String> {
+        let line = line.strip_prefix('|').unwrap_or(line);
+        let line = match line.strip_suffix('|') {
+            Some(rest) if !MarkdownList::is_escaped(line, line.len() - 1) => rest,
+            _ => line,
+        };
+        MarkdownList::split_unescaped(line, "|")
+            .into_iter()
+            .map(|cell| {
+                MarkdownList::unescape(MarkdownList::strip_emphasis(cell.trim()))
+                    .replace(LINE_BREAK, "\n")
+            })
+            .collect()
+    }
+
+    fn is_delimiter(cells: &[String]) -> bool {
+        cells.iter().all(|cell| {
+            let cell = cell.trim().trim_start_matches(':').trim_end_matches(':');
+            !cell.is_empty() && cell.chars().all(|c| c == '-')
+        })
+    }
+}
\ No newline at end of file
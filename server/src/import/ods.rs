@@ -0,0 +1,120 @@
+use std::io::Cursor;
+use std::io::Write;
+
+use quick_xml::escape::escape;
+use zip::write::SimpleFileOptions;
+use zip::CompressionMethod;
+use zip::ZipWriter;
+
+use crate::import::error::ImportError;
+
+const MIMETYPE: &str = "application/vnd.oasis.opendocument.spreadsheet";
+const MANIFEST: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<manifest:manifest xmlns:manifest="urn:oasis:names:tc:opendocument:xmlns:manifest:1.0" manifest:version="1.2">
+ <manifest:file-entry manifest:full-path="/" manifest:version="1.2" manifest:media-type="application/vnd.oasis.opendocument.spreadsheet"/>
+ <manifest:file-entry manifest:full-path="content.xml" manifest:media-type="text/xml"/>
+</manifest:manifest>"#;
+const CONTENT_START: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<office:document-content xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" xmlns:table="urn:oasis:names:tc:opendocument:xmlns:table:1.0" xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0" office:version="1.2"><office:body><office:spreadsheet>"#;
+const CONTENT_END: &str = "</office:spreadsheet></office:body></office:document-content>";
+
+pub enum OdsCell {
+    Text(String),
+    Number(f64),
+}
+
+pub struct OdsSheet {
+    pub name: String,
+    pub rows: Vec<Vec<OdsCell>>,
+}
+
+pub struct OdsWriter;
+
+impl OdsWriter {
+    pub fn write(sheets: &[OdsSheet]) -> Result<Vec<u8>, ImportError> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+
+        // The mimetype entry must come first and be stored uncompressed.
+        writer.start_file(
+            "mimetype",
+            SimpleFileOptions::default().compression_method(CompressionMethod::Stored),
+        )?;
+        writer.write_all(MIMETYPE.as_bytes())?;
+
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+        writer.start_file("META-INF/manifest.xml", options)?;
+        writer.write_all(MANIFEST.as_bytes())?;
+
+        writer.start_file("content.xml", options)?;
+        writer.write_all(Self::content(sheets).as_bytes())?;
+
+        Ok(writer.finish()?.into_inner())
+    }
+
+    fn content(sheets: &[OdsSheet]) -> String {
+        let mut xml = String::from(CONTENT_START);
+        for sheet in sheets {
+            xml.push_str(&format!(r#"<table:table table:name="{}">"#, escape(&sheet.name)));
+            for row in &sheet.rows {
+                xml.push_str("<table:table-row>");
+                for cell in row {
+                    Self::push_cell(&mut xml, cell);
+                }
+                xml.push_str("</table:table-row>");
+            }
+            xml.push_str("</table:table>");
+        }
+        xml.push_str(CONTENT_END);
+        xml
+    }
+
+    fn push_cell(xml: &mut String, cell: &OdsCell) {
+        match cell {
+            OdsCell::Text(value) if value.is_empty() => xml.push_str("<table:table-cell/>"),
+            OdsCell::Text(value) => {
+                xml.push_str(r#"<table:table-cell office:value-type="string">"#);
+                for line in value.split('\n') {
+                    xml.push_str("<text:p>");
+                    Self::push_text(xml, line.trim_end_matches('\r'));
+                    xml.push_str("</text:p>");
+                }
+                xml.push_str("</table:table-cell>");
+            }
+            OdsCell::Number(value) => xml.push_str(&format!(
+                r#"<table:table-cell office:value-type="float" office:value="{0}"><text:p>{0}</text:p></table:table-cell>"#,
+                value
+            )),
+        }
+    }
+
+    // ODF collapses runs of spaces and drops leading ones, so extra spaces become <text:s/>.
+    fn push_text(xml: &mut String, line: &str) {
+        let mut spaces = 0;
+        let mut at_start = true;
+        for (idx, segment) in line.split(' ').enumerate() {
+            if idx > 0 {
+                spaces += 1;
+            }
+            if segment.is_empty() {
+                continue;
+            }
+            Self::push_spaces(xml, spaces, at_start);
+            xml.push_str(&escape(segment));
+            spaces = 0;
+            at_start = false;
+        }
+        Self::push_spaces(xml, spaces, true);
+    }
+
+    fn push_spaces(xml: &mut String, count: usize, escape_all: bool) {
+        let literal = usize::from(count > 0 && !escape_all);
+        if literal == 1 {
+            xml.push(' ');
+        }
+        match count - literal {
+            0 => {}
+            1 => xml.push_str("<text:s/>"),
+            n => xml.push_str(&format!(r#"<text:s text:c="{}"/>"#, n)),
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,130 @@
+use csv::ReaderBuilder;
+use csv::StringRecord;
+
+use crate::import::collector::ChapterCollector;
+use crate::import::columns::ColumnIndices;
+use crate::import::columns::ColumnMap;
+use crate::import::data::ImportChapter;
+use crate::import::data::ImportWordbook;
+use crate::import::error::ImportError;
+
+const BOM: &str = "\u{feff}";
+const CANDIDATE_DELIMITERS: [u8; 4] = [b',', b'\t', b';', b'|'];
+const QUOTE: u8 = b'"';
+
+pub struct CsvParser;
+
+impl CsvParser {
+    pub fn parse_wordbook(
+        content: &str,
+        wordbook_name: String,
+        column_map: &ColumnMap,
+    ) -> Result<ImportWordbook, ImportError> {
+        let (header, records) = Self::read_records(content)?;
+        let col_indices = ColumnIndices::from_header(Self::cells(&header), true, column_map)?;
+
+        let mut collector = ChapterCollector::default();
+
+        for (line, record) in records {
+            let cell = |idx: usize| Self::get_field(&record, idx);
+            let chapter_name = col_indices.read_chapter_name(cell);
+            let chapter_order = col_indices.read_chapter_order(line, cell)?;
+            let word = col_indices.read_word(line, cell);
+
+            collector.push(chapter_name, chapter_order, word);
+        }
+
+        Ok(ImportWordbook {
+            name: wordbook_name,
+            description: None,
+            chapters: collector.into_chapters(),
+        })
+    }
+
+    pub fn parse_chapter(
+        content: &str,
+        chapter_name: String,
+        column_map: &ColumnMap,
+    ) -> Result<ImportChapter, ImportError> {
+        let (header, records) = Self::read_records(content)?;
+        let col_indices = ColumnIndices::from_header(Self::cells(&header), false, column_map)?;
+
+        let mut words = Vec::new();
+
+        for (line, record) in records {
+            words.push(col_indices.read_word(line, |idx| Self::get_field(&record, idx)));
+        }
+
+        Ok(ImportChapter::with_words(chapter_name, words))
+    }
+
+    fn read_records(
+        content: &str,
+    ) -> Result<(StringRecord, Vec<(usize, StringRecord)>), ImportError> {
+        let content = content.strip_prefix(BOM).unwrap_or(content);
+
+        let mut reader = ReaderBuilder::new()
+            .delimiter(Self::sniff_delimiter(content))
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(content.as_bytes());
+
+        let mut records = reader.records();
+        let header = records
+            .next()
+            .transpose()?
+            .ok_or_else(|| ImportError::InvalidFormat("Empty file".to_string()))?;
+
+        let mut rows = Vec::new();
+        for record in records {
+            let record = record?;
+            if record.iter().all(|field| field.trim().is_empty()) {
+                continue;
+            }
+            let line = record.position().map(|p| p.line() as usize).unwrap_or(0);
+            rows.push((line, record));
+        }
+
+        Ok((header, rows))
+    }
+
+    fn sniff_delimiter(content: &str) -> u8 {
+        let mut counts = [0usize; CANDIDATE_DELIMITERS.len()];
+        let mut in_quotes = false;
+
+        for byte in content.bytes() {
+            match byte {
+                QUOTE => in_quotes = !in_quotes,
+                b'\n' if !in_quotes => break,
+                _ if !in_quotes => {
+                    if let Some(idx) = CANDIDATE_DELIMITERS.iter().position(|d| *d == byte) {
+                        counts[idx] += 1;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut best = (CANDIDATE_DELIMITERS[0], 0);
+        for (delimiter, count) in CANDIDATE_DELIMITERS.into_iter().zip(counts) {
+            if count > best.1 {
+                best = (delimiter, count);
+            }
+        }
+        best.0
+    }
+
+    fn cells(record: &StringRecord) -> impl Iterator<Item = Option<&str>> {
+        record
+            .iter()
+            .map(|field| Some(field.trim()).filter(|field| !field.is_empty()))
+    }
+
+    fn get_field(record: &StringRecord, idx: usize) -> Option<String> {
+        record
+            .get(idx)
+            .map(str::trim)
+            .filter(|field| !field.is_empty())
+            .map(String::from)
+    }
+}
\ No newline at end of file
@@ -1,25 +1,61 @@
+use calamine::open_workbook_auto_from_rs;
 use calamine::Data;
 use calamine::Reader;
-use calamine::Xlsx;
-use std::collections::HashMap;
+use calamine::Sheets;
+use serde::Deserialize;
 use std::io::Cursor;
 
+use crate::import::collector::ChapterCollector;
+use crate::import::columns::ColumnIndices;
+use crate::import::columns::ColumnMap;
 use crate::import::data::ImportChapter;
-use crate::import::data::ImportWord;
 use crate::import::data::ImportWordbook;
 use crate::import::error::ImportError;
 
-const HEADER_CHAPTER_NAME: &str = "chapter_name";
-const HEADER_SOURCE: &str = "source";
-const HEADER_TRANSLATION: &str = "translation";
-const HEADER_NOTE: &str = "note";
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SheetLayout {
+    #[default]
+    Single,
+    PerChapter,
+}
 
 pub struct ExcelParser;
 
+impl SheetLayout {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "single" => Some(Self::Single),
+            "per_chapter" => Some(Self::PerChapter),
+            _ => None,
+        }
+    }
+}
+
 impl ExcelParser {
-    pub fn parse_wordbook(data: &[u8], wordbook_name: String) -> Result<ImportWordbook, ImportError> {
-        let cursor = Cursor::new(data);
-        let mut workbook: Xlsx<_> = Xlsx::new(cursor)?;
+    pub fn parse_wordbook(
+        data: &[u8],
+        wordbook_name: String,
+        column_map: &ColumnMap,
+        layout: SheetLayout,
+    ) -> Result<ImportWordbook, ImportError> {
+        let chapters = match layout {
+            SheetLayout::Single => Self::parse_single_sheet(data, column_map)?,
+            SheetLayout::PerChapter => Self::parse_sheet_chapters(data, column_map)?,
+        };
+
+        Ok(ImportWordbook {
+            name: wordbook_name,
+            description: None,
+            chapters,
+        })
+    }
+
+    fn parse_single_sheet(
+        data: &[u8],
+        column_map: &ColumnMap,
+    ) -> Result<Vec<ImportChapter>, ImportError> {
+        let mut workbook = Self::open(data)?;
 
         let sheet_name = workbook
             .sheet_names()
@@ -36,44 +72,71 @@ impl ExcelParser {
             .next()
             .ok_or_else(|| ImportError::InvalidFormat("Empty sheet".to_string()))?;
 
-        let col_indices = Self::parse_header(header, true)?;
+        let col_indices = Self::parse_header(header, true, column_map)?;
 
-        let mut chapters_map: HashMap<String, Vec<ImportWord>> = HashMap::new();
+        let mut collector = ChapterCollector::default();
 
         for (row_idx, row) in rows.enumerate() {
-            let chapter_name = Self::get_cell_string(row, col_indices.chapter_name.unwrap_or(0))
-                .ok_or_else(|| ImportError::invalid_data(row_idx + 2, "Missing chapter_name"))?;
+            if Self::is_blank(row) {
+                continue;
+            }
+            let cell = |idx: usize| Self::get_cell_string(row, idx);
+            let chapter_name = col_indices.read_chapter_name(cell);
+            let chapter_order = col_indices.read_chapter_order(row_idx + 2, cell)?;
+            let word = col_indices.read_word(row_idx + 2, cell);
 
-            let source = Self::get_cell_string(row, col_indices.source)
-                .ok_or_else(|| ImportError::invalid_data(row_idx + 2, "Missing source"))?;
+            collector.push(chapter_name, chapter_order, word);
+        }
 
-            let translation = Self::get_cell_string(row, col_indices.translation)
-                .ok_or_else(|| ImportError::invalid_data(row_idx + 2, "Missing translation"))?;
+        Ok(collector.into_chapters())
+    }
 
-            let note = col_indices.note.and_then(|idx| Self::get_cell_string(row, idx));
+    fn parse_sheet_chapters(
+        data: &[u8],
+        column_map: &ColumnMap,
+    ) -> Result<Vec<ImportChapter>, ImportError> {
+        let mut workbook = Self::open(data)?;
 
-            let word = ImportWord::new(source, translation, note);
-            chapters_map
-                .entry(chapter_name)
-                .or_default()
-                .push(word);
-        }
+        let mut chapters = Vec::new();
 
-        let chapters: Vec<ImportChapter> = chapters_map
-            .into_iter()
-            .map(|(name, words)| ImportChapter::with_words(name, words))
-            .collect();
+        for sheet_name in workbook.sheet_names() {
+            let range = workbook
+                .worksheet_range(&sheet_name)
+                .map_err(|e| ImportError::ExcelError(e.to_string()))?;
 
-        Ok(ImportWordbook {
-            name: wordbook_name,
-            description: None,
-            chapters,
-        })
+            let mut rows = range.rows();
+            let Some(header) = rows.next() else {
+                continue;
+            };
+
+            let col_indices = Self::parse_header(header, false, column_map).map_err(|e| {
+                ImportError::InvalidFormat(format!("Sheet '{}': {}", sheet_name, e))
+            })?;
+
+            let mut words = Vec::new();
+
+            for (row_idx, row) in rows.enumerate() {
+                if Self::is_blank(row) {
+                    continue;
+                }
+                words.push(col_indices.read_word(row_idx + 2, |idx| Self::get_cell_string(row, idx)));
+            }
+
+            chapters.push(ImportChapter::with_words(sheet_name, words));
+        }
+
+        if chapters.is_empty() {
+            return Err(ImportError::InvalidFormat("No sheets found".to_string()));
+        }
+        Ok(chapters)
     }
 
-    pub fn parse_chapter(data: &[u8], chapter_name: String) -> Result<ImportChapter, ImportError> {
-        let cursor = Cursor::new(data);
-        let mut workbook: Xlsx<_> = Xlsx::new(cursor)?;
+    pub fn parse_chapter(
+        data: &[u8],
+        chapter_name: String,
+        column_map: &ColumnMap,
+    ) -> Result<ImportChapter, ImportError> {
+        let mut workbook = Self::open(data)?;
 
         let sheet_name = workbook
             .sheet_names()
@@ -90,45 +153,43 @@ impl ExcelParser {
             .next()
             .ok_or_else(|| ImportError::InvalidFormat("Empty sheet".to_string()))?;
 
-        let col_indices = Self::parse_header(header, false)?;
+        let col_indices = Self::parse_header(header, false, column_map)?;
 
         let mut words = Vec::new();
 
         for (row_idx, row) in rows.enumerate() {
-            let source = Self::get_cell_string(row, col_indices.source)
-                .ok_or_else(|| ImportError::invalid_data(row_idx + 2, "Missing source"))?;
-
-            let translation = Self::get_cell_string(row, col_indices.translation)
-                .ok_or_else(|| ImportError::invalid_data(row_idx + 2, "Missing translation"))?;
-
-            let note = col_indices.note.and_then(|idx| Self::get_cell_string(row, idx));
-
-            words.push(ImportWord::new(source, translation, note));
+            if Self::is_blank(row) {
+                continue;
+            }
+            words.push(col_indices.read_word(row_idx + 2, |idx| Self::get_cell_string(row, idx)));
         }
 
         Ok(ImportChapter::with_words(chapter_name, words))
     }
 
-    fn parse_header(header: &[Data], require_chapter: bool) -> Result<ColumnIndices, ImportError> {
-        let mut indices = ColumnIndices::default();
-
-        for (idx, cell) in header.iter().enumerate() {
-            if let Some(name) = Self::data_to_string(cell) {
-                match name.to_lowercase().trim() {
-                    HEADER_CHAPTER_NAME => indices.chapter_name = Some(idx),
-                    HEADER_SOURCE => indices.source = idx,
-                    HEADER_TRANSLATION => indices.translation = idx,
-                    HEADER_NOTE => indices.note = Some(idx),
-                    _ => {}
-                }
-            }
-        }
+    // The workbook type (xls, xlsx, xlsb or ods) is detected from the content, not the extension.
+    fn open(data: &[u8]) -> Result<Sheets<Cursor<&[u8]>>, ImportError> {
+        open_workbook_auto_from_rs(Cursor::new(data)).map_err(|_| {
+            ImportError::InvalidFormat(
+                "Not a readable spreadsheet: expected an xls, xlsx, xlsb or ods workbook".to_string(),
+            )
+        })
+    }
 
-        if require_chapter && indices.chapter_name.is_none() {
-            return Err(ImportError::MissingField("chapter_name".to_string()));
-        }
+    fn parse_header(
+        header: &[Data],
+        require_chapter: bool,
+        column_map: &ColumnMap,
+    ) -> Result<ColumnIndices, ImportError> {
+        ColumnIndices::from_header(
+            header.iter().map(Self::data_to_string),
+            require_chapter,
+            column_map,
+        )
+    }
 
-        Ok(indices)
+    fn is_blank(row: &[Data]) -> bool {
+        row.iter().all(|data| Self::data_to_string(data).is_none())
     }
 
     fn get_cell_string(row: &[Data], idx: usize) -> Option<String> {
@@ -154,12 +215,4 @@ impl ExcelParser {
             Data::Error(_) | Data::Empty => None,
         }
     }
-}
-
-#[derive(Default)]
-struct ColumnIndices {
-    chapter_name: Option<usize>,
-    source: usize,
-    translation: usize,
-    note: Option<usize>,
 }
\ No newline at end of file
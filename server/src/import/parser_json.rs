@@ -5,15 +5,11 @@ use crate::import::error::ImportError;
 pub struct JsonParser;
 
 impl JsonParser {
-    pub fn parse_wordbook(data: &[u8]) -> Result<ImportWordbook, ImportError> {
-        let content = std::str::from_utf8(data)
-            .map_err(|e| ImportError::ParseError(e.to_string()))?;
+    pub fn parse_wordbook(content: &str) -> Result<ImportWordbook, ImportError> {
         serde_json::from_str(content).map_err(ImportError::from)
     }
 
-    pub fn parse_chapter(data: &[u8]) -> Result<ImportChapter, ImportError> {
-        let content = std::str::from_utf8(data)
-            .map_err(|e| ImportError::ParseError(e.to_string()))?;
+    pub fn parse_chapter(content: &str) -> Result<ImportChapter, ImportError> {
         serde_json::from_str(content).map_err(ImportError::from)
     }
 }
\ No newline at end of file
@@ -7,16 +7,12 @@ use crate::import::error::ImportError;
 pub struct XmlParser;
 
 impl XmlParser {
-    pub fn parse_wordbook(data: &[u8]) -> Result<ImportWordbook, ImportError> {
-        let content = std::str::from_utf8(data)
-            .map_err(|e| ImportError::ParseError(e.to_string()))?;
+    pub fn parse_wordbook(content: &str) -> Result<ImportWordbook, ImportError> {
         let xml_wordbook: XmlWordbook = quick_xml::de::from_str(content)?;
         Ok(ImportWordbook::from(xml_wordbook))
     }
 
-    pub fn parse_chapter(data: &[u8]) -> Result<ImportChapter, ImportError> {
-        let content = std::str::from_utf8(data)
-            .map_err(|e| ImportError::ParseError(e.to_string()))?;
+    pub fn parse_chapter(content: &str) -> Result<ImportChapter, ImportError> {
         let xml_chapter: XmlChapter = quick_xml::de::from_str(content)?;
         Ok(ImportChapter::from(xml_chapter))
     }
@@ -0,0 +1,35 @@
+use std::path::PathBuf;
+
+use sqlx::sqlite::SqliteConnectOptions;
+use sqlx::sqlite::SqliteJournalMode;
+use sqlx::Connection;
+use sqlx::SqliteConnection;
+use uuid::Uuid;
+
+// A SQLite file in the temp directory, removed when dropped. sqlx cannot open a
+// database from memory, so uploaded databases are written here first.
+pub struct TempDatabase {
+    pub path: PathBuf,
+}
+
+impl TempDatabase {
+    pub fn new(extension: &str) -> Self {
+        Self {
+            path: std::env::temp_dir().join(format!("plain-word-{}.{}", Uuid::new_v4(), extension)),
+        }
+    }
+
+    pub async fn connect(&self, create: bool) -> Result<SqliteConnection, sqlx::Error> {
+        let options = SqliteConnectOptions::new()
+            .filename(&self.path)
+            .create_if_missing(create)
+            .journal_mode(SqliteJournalMode::Delete);
+        SqliteConnection::connect_with(&options).await
+    }
+}
+
+impl Drop for TempDatabase {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
\ No newline at end of file
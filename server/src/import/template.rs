@@ -1,6 +1,8 @@
 use rust_xlsxwriter::Workbook;
 
 use crate::import::data::ImportChapter;
+use crate::import::data::ImportExample;
+use crate::import::data::ImportTag;
 use crate::import::data::ImportWord;
 use crate::import::data::ImportWordbook;
 
@@ -8,6 +10,10 @@ const HEADER_CHAPTER_NAME: &str = "chapter_name";
 const HEADER_SOURCE: &str = "source";
 const HEADER_TRANSLATION: &str = "translation";
 const HEADER_NOTE: &str = "note";
+const HEADER_PHONETIC: &str = "phonetic";
+const HEADER_PART_OF_SPEECH: &str = "part_of_speech";
+const HEADER_EXAMPLES: &str = "examples";
+const HEADER_TAGS: &str = "tags";
 const SAMPLE_CHAPTER_1: &str = "Chapter 1";
 const SAMPLE_CHAPTER_2: &str = "Chapter 2";
 const SAMPLE_SOURCE_1: &str = "source_text_1";
@@ -18,6 +24,15 @@ const SAMPLE_TRANSLATION_2: &str = "translation_text_2";
 const SAMPLE_TRANSLATION_3: &str = "translation_text_3";
 const SAMPLE_NOTE_1: &str = "note_1";
 const SAMPLE_NOTE_3: &str = "note_3";
+const SAMPLE_PHONETIC_1: &str = "/ˈfəʊnɛtɪk/";
+const SAMPLE_PART_OF_SPEECH_1: &str = "n.";
+const SAMPLE_EXAMPLE_1: &str = "example_sentence_1";
+const SAMPLE_EXAMPLE_TRANSLATION_1: &str = "example_translation_1";
+const SAMPLE_EXAMPLES_CELL_1: &str = "example_sentence_1 | example_translation_1";
+const SAMPLE_TAG_1: &str = "tag_1";
+const SAMPLE_TAG_2: &str = "tag_2";
+const SAMPLE_TAG_COLOR_2: &str = "#3b82f6";
+const SAMPLE_TAGS_CELL_1: &str = "tag_1; tag_2#3b82f6";
 
 pub struct TemplateGenerator;
 
@@ -29,11 +44,7 @@ impl TemplateGenerator {
             chapters: vec![ImportChapter::with_words(
                 "chapter_name".to_string(),
                 vec![
-                    ImportWord::new(
-                        SAMPLE_SOURCE_1.to_string(),
-                        SAMPLE_TRANSLATION_1.to_string(),
-                        Some(SAMPLE_NOTE_1.to_string()),
-                    ),
+                    Self::sample_word(),
                     ImportWord::new(
                         SAMPLE_SOURCE_2.to_string(),
                         SAMPLE_TRANSLATION_2.to_string(),
@@ -49,11 +60,7 @@ impl TemplateGenerator {
         let template = ImportChapter::with_words(
             "chapter_name".to_string(),
             vec![
-                ImportWord::new(
-                    SAMPLE_SOURCE_1.to_string(),
-                    SAMPLE_TRANSLATION_1.to_string(),
-                    Some(SAMPLE_NOTE_1.to_string()),
-                ),
+                Self::sample_word(),
                 ImportWord::new(
                     SAMPLE_SOURCE_2.to_string(),
                     SAMPLE_TRANSLATION_2.to_string(),
@@ -64,8 +71,31 @@ impl TemplateGenerator {
         serde_json::to_string_pretty(&template).unwrap_or_default()
     }
 
+    fn sample_word() -> ImportWord {
+        ImportWord::new(
+            SAMPLE_SOURCE_1.to_string(),
+            SAMPLE_TRANSLATION_1.to_string(),
+            Some(SAMPLE_NOTE_1.to_string()),
+        )
+        .with_details(
+            Some(SAMPLE_PHONETIC_1.to_string()),
+            Some(SAMPLE_PART_OF_SPEECH_1.to_string()),
+            vec![ImportExample {
+                sentence: SAMPLE_EXAMPLE_1.to_string(),
+                translation: Some(SAMPLE_EXAMPLE_TRANSLATION_1.to_string()),
+            }],
+        )
+        .with_tags(vec![
+            ImportTag::named(SAMPLE_TAG_1.to_string()),
+            ImportTag {
+                name: SAMPLE_TAG_2.to_string(),
+                color: Some(SAMPLE_TAG_COLOR_2.to_string()),
+            },
+        ])
+    }
+
     pub fn xml_wordbook_template() -> String {
-        r#"<?xml version="1.0" encoding="UTF-8"?>
+        r##"<?xml version="1.0" encoding="UTF-8"?>
 <wordbook>
     <name>wordbook_name</name>
     <description>wordbook_description</description>
@@ -75,30 +105,78 @@ impl TemplateGenerator {
             <source>source_text_1</source>
             <translation>translation_text_1</translation>
             <note>note_1</note>
+            <phonetic>/ˈfəʊnɛtɪk/</phonetic>
+            <part_of_speech>n.</part_of_speech>
+            <example>
+                <sentence>example_sentence_1</sentence>
+                <translation>example_translation_1</translation>
+            </example>
+            <tag>tag_1</tag>
+            <tag color="#3b82f6">tag_2</tag>
         </word>
         <word>
             <source>source_text_2</source>
             <translation>translation_text_2</translation>
         </word>
     </chapter>
-</wordbook>"#
+</wordbook>"##
             .to_string()
     }
 
     pub fn xml_chapter_template() -> String {
-        r#"<?xml version="1.0" encoding="UTF-8"?>
+        r##"<?xml version="1.0" encoding="UTF-8"?>
 <chapter>
     <name>chapter_name</name>
     <word>
         <source>source_text_1</source>
         <translation>translation_text_1</translation>
         <note>note_1</note>
+        <phonetic>/ˈfəʊnɛtɪk/</phonetic>
+        <part_of_speech>n.</part_of_speech>
+        <example>
+            <sentence>example_sentence_1</sentence>
+            <translation>example_translation_1</translation>
+        </example>
+        <tag>tag_1</tag>
+        <tag color="#3b82f6">tag_2</tag>
     </word>
     <word>
         <source>source_text_2</source>
         <translation>translation_text_2</translation>
     </word>
-</chapter>"#
+</chapter>"##
+            .to_string()
+    }
+
+    pub fn markdown_wordbook_template() -> String {
+        r##"# wordbook_name
+
+wordbook_description
+
+## Chapter 1
+
+- source_text_1 — translation_text_1 (note_1)
+- source_text_2 — translation_text_2
+
+## Chapter 2
+
+| source | translation | note | phonetic | part_of_speech | examples | tags |
+| --- | --- | --- | --- | --- | --- | --- |
+| source_text_3 | translation_text_3 | note_3 | /ˈfəʊnɛtɪk/ | n. | example_sentence_1 \| example_translation_1 | tag_1; tag_2#3b82f6 |
+"##
+            .to_string()
+    }
+
+    pub fn markdown_chapter_template() -> String {
+        r##"# chapter_name
+
+- source_text_1 — translation_text_1 (note_1)
+- source_text_2 — translation_text_2
+
+| source | translation | note | phonetic | part_of_speech | examples | tags |
+| --- | --- | --- | --- | --- | --- | --- |
+| source_text_3 | translation_text_3 | note_3 | /ˈfəʊnɛtɪk/ | n. | example_sentence_1 \| example_translation_1 | tag_1; tag_2#3b82f6 |
+"##
             .to_string()
     }
 
@@ -112,18 +190,18 @@ impl TemplateGenerator {
 
     pub fn csv_wordbook_template() -> String {
         let mut content = String::new();
-        content.push_str(&format!("{},{},{},{}\n", HEADER_CHAPTER_NAME, HEADER_SOURCE, HEADER_TRANSLATION, HEADER_NOTE));
-        content.push_str(&format!("{},{},{},{}\n", SAMPLE_CHAPTER_1, SAMPLE_SOURCE_1, SAMPLE_TRANSLATION_1, SAMPLE_NOTE_1));
-        content.push_str(&format!("{},{},{},\n", SAMPLE_CHAPTER_1, SAMPLE_SOURCE_2, SAMPLE_TRANSLATION_2));
-        content.push_str(&format!("{},{},{},{}\n", SAMPLE_CHAPTER_2, SAMPLE_SOURCE_3, SAMPLE_TRANSLATION_3, SAMPLE_NOTE_3));
+        content.push_str(&format!("{},{},{},{},{},{},{},{}\n", HEADER_CHAPTER_NAME, HEADER_SOURCE, HEADER_TRANSLATION, HEADER_NOTE, HEADER_PHONETIC, HEADER_PART_OF_SPEECH, HEADER_EXAMPLES, HEADER_TAGS));
+        content.push_str(&format!("{},{},{},{},{},{},{},{}\n", SAMPLE_CHAPTER_1, SAMPLE_SOURCE_1, SAMPLE_TRANSLATION_1, SAMPLE_NOTE_1, SAMPLE_PHONETIC_1, SAMPLE_PART_OF_SPEECH_1, SAMPLE_EXAMPLES_CELL_1, SAMPLE_TAGS_CELL_1));
+        content.push_str(&format!("{},{},{},,,,,\n", SAMPLE_CHAPTER_1, SAMPLE_SOURCE_2, SAMPLE_TRANSLATION_2));
+        content.push_str(&format!("{},{},{},{},,,,\n", SAMPLE_CHAPTER_2, SAMPLE_SOURCE_3, SAMPLE_TRANSLATION_3, SAMPLE_NOTE_3));
         content
     }
 
     pub fn csv_chapter_template() -> String {
         let mut content = String::new();
-        content.push_str(&format!("{},{},{}\n", HEADER_SOURCE, HEADER_TRANSLATION, HEADER_NOTE));
-        content.push_str(&format!("{},{},{}\n", SAMPLE_SOURCE_1, SAMPLE_TRANSLATION_1, SAMPLE_NOTE_1));
-        content.push_str(&format!("{},{},\n", SAMPLE_SOURCE_2, SAMPLE_TRANSLATION_2));
+        content.push_str(&format!("{},{},{},{},{},{},{}\n", HEADER_SOURCE, HEADER_TRANSLATION, HEADER_NOTE, HEADER_PHONETIC, HEADER_PART_OF_SPEECH, HEADER_EXAMPLES, HEADER_TAGS));
+        content.push_str(&format!("{},{},{},{},{},{},{}\n", SAMPLE_SOURCE_1, SAMPLE_TRANSLATION_1, SAMPLE_NOTE_1, SAMPLE_PHONETIC_1, SAMPLE_PART_OF_SPEECH_1, SAMPLE_EXAMPLES_CELL_1, SAMPLE_TAGS_CELL_1));
+        content.push_str(&format!("{},{},,,,,\n", SAMPLE_SOURCE_2, SAMPLE_TRANSLATION_2));
         content
     }
 
@@ -136,11 +214,19 @@ impl TemplateGenerator {
             let _ = worksheet.write_string(0, 1, HEADER_SOURCE);
             let _ = worksheet.write_string(0, 2, HEADER_TRANSLATION);
             let _ = worksheet.write_string(0, 3, HEADER_NOTE);
+            let _ = worksheet.write_string(0, 4, HEADER_PHONETIC);
+            let _ = worksheet.write_string(0, 5, HEADER_PART_OF_SPEECH);
+            let _ = worksheet.write_string(0, 6, HEADER_EXAMPLES);
+            let _ = worksheet.write_string(0, 7, HEADER_TAGS);
 
             let _ = worksheet.write_string(1, 0, SAMPLE_CHAPTER_1);
             let _ = worksheet.write_string(1, 1, SAMPLE_SOURCE_1);
             let _ = worksheet.write_string(1, 2, SAMPLE_TRANSLATION_1);
             let _ = worksheet.write_string(1, 3, SAMPLE_NOTE_1);
+            let _ = worksheet.write_string(1, 4, SAMPLE_PHONETIC_1);
+            let _ = worksheet.write_string(1, 5, SAMPLE_PART_OF_SPEECH_1);
+            let _ = worksheet.write_string(1, 6, SAMPLE_EXAMPLES_CELL_1);
+            let _ = worksheet.write_string(1, 7, SAMPLE_TAGS_CELL_1);
 
             let _ = worksheet.write_string(2, 0, SAMPLE_CHAPTER_1);
             let _ = worksheet.write_string(2, 1, SAMPLE_SOURCE_2);
@@ -154,10 +240,18 @@ impl TemplateGenerator {
             let _ = worksheet.write_string(0, 0, HEADER_SOURCE);
             let _ = worksheet.write_string(0, 1, HEADER_TRANSLATION);
             let _ = worksheet.write_string(0, 2, HEADER_NOTE);
+            let _ = worksheet.write_string(0, 3, HEADER_PHONETIC);
+            let _ = worksheet.write_string(0, 4, HEADER_PART_OF_SPEECH);
+            let _ = worksheet.write_string(0, 5, HEADER_EXAMPLES);
+            let _ = worksheet.write_string(0, 6, HEADER_TAGS);
 
             let _ = worksheet.write_string(1, 0, SAMPLE_SOURCE_1);
             let _ = worksheet.write_string(1, 1, SAMPLE_TRANSLATION_1);
             let _ = worksheet.write_string(1, 2, SAMPLE_NOTE_1);
+            let _ = worksheet.write_string(1, 3, SAMPLE_PHONETIC_1);
+            let _ = worksheet.write_string(1, 4, SAMPLE_PART_OF_SPEECH_1);
+            let _ = worksheet.write_string(1, 5, SAMPLE_EXAMPLES_CELL_1);
+            let _ = worksheet.write_string(1, 6, SAMPLE_TAGS_CELL_1);
 
             let _ = worksheet.write_string(2, 0, SAMPLE_SOURCE_2);
             let _ = worksheet.write_string(2, 1, SAMPLE_TRANSLATION_2);
@@ -0,0 +1,315 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::import::data::ImportChapter;
+use crate::import::data::ImportTag;
+use crate::import::data::ImportWord;
+use crate::import::data::ImportWordbook;
+
+const MAX_NAME_LENGTH: usize = 100;
+const MAX_SOURCE_LENGTH: usize = 500;
+const MAX_TRANSLATION_LENGTH: usize = 500;
+const MAX_PHONETIC_LENGTH: usize = 200;
+const MAX_PART_OF_SPEECH_LENGTH: usize = 50;
+const MAX_EXAMPLES: usize = 50;
+const MAX_EXAMPLE_LENGTH: usize = 1000;
+const MAX_TAG_LENGTH: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueSeverity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportIssue {
+    pub severity: IssueSeverity,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub row: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chapter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    pub field: &'static str,
+    pub message: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ImportReport {
+    pub errors: Vec<ImportIssue>,
+    pub warnings: Vec<ImportIssue>,
+}
+
+struct WordLocation<'a> {
+    chapter: &'a str,
+    position: usize,
+    row: Option<usize>,
+}
+
+pub struct ImportValidator;
+
+impl ImportValidator {
+    pub fn validate_wordbook(wordbook: &ImportWordbook) -> ImportReport {
+        let mut report = ImportReport::default();
+        Self::check_name(&mut report, "name", &wordbook.name);
+
+        let mut seen = HashMap::new();
+        for chapter in &wordbook.chapters {
+            Self::check_chapter(&mut report, chapter, &mut seen);
+        }
+        report
+    }
+
+    pub fn validate_chapter(chapter: &ImportChapter) -> ImportReport {
+        let mut report = ImportReport::default();
+        Self::check_chapter(&mut report, chapter, &mut HashMap::new());
+        report
+    }
+
+    // Some sources (Kindle lookups) carry no translations. Words still missing one after
+    // the dictionary are left out and listed as warnings, so a few misses do not fail the
+    // whole import.
+    pub fn skip_untranslated(chapter: &mut ImportChapter) -> ImportReport {
+        let mut report = ImportReport::default();
+        let mut position = 0;
+        chapter.words.retain(|word| {
+            position += 1;
+            if !word.translation.trim().is_empty() || word.source.trim().is_empty() {
+                return true;
+            }
+            report.push(
+                ImportIssue::warning("translation", "No translation found; word skipped".to_string())
+                    .in_chapter(&chapter.name)
+                    .at_position(position)
+                    .at_row(word.row)
+                    .for_source(&word.source),
+            );
+            false
+        });
+        report
+    }
+
+    fn check_name(report: &mut ImportReport, field: &'static str, name: &str) {
+        if name.trim().is_empty() {
+            report.push(ImportIssue::error(field, format!("Missing {}", field)));
+        } else if Self::too_long(name, MAX_NAME_LENGTH) {
+            report.push(ImportIssue::error(
+                field,
+                format!("{} exceeds {} characters", field, MAX_NAME_LENGTH),
+            ));
+        }
+    }
+
+    fn check_chapter<'a>(
+        report: &mut ImportReport,
+        chapter: &'a ImportChapter,
+        seen: &mut HashMap<String, WordLocation<'a>>,
+    ) {
+        if chapter.name.trim().is_empty() && !chapter.words.is_empty() {
+            for word in &chapter.words {
+                report.push(
+                    ImportIssue::error("chapter_name", "Missing chapter_name".to_string())
+                        .at_row(word.row)
+                        .for_source(&word.source),
+                );
+            }
+        } else {
+            let mut chapter_report = ImportReport::default();
+            Self::check_name(&mut chapter_report, "chapter_name", &chapter.name);
+            report.extend(chapter_report, |issue| issue.in_chapter(&chapter.name));
+        }
+
+        for (idx, word) in chapter.words.iter().enumerate() {
+            let location = WordLocation {
+                chapter: &chapter.name,
+                position: idx + 1,
+                row: word.row,
+            };
+
+            let mut word_report = ImportReport::default();
+            Self::check_word(&mut word_report, word);
+
+            let key = ImportWord::normalize_source(&word.source);
+            if !key.is_empty() {
+                match seen.get(&key) {
+                    Some(first) => word_report.push(ImportIssue::warning(
+                        "source",
+                        format!("Duplicate of {}", first.describe(&location)),
+                    )),
+                    None => {
+                        seen.insert(key, location);
+                    }
+                }
+            }
+
+            report.extend(word_report, |issue| {
+                issue
+                    .in_chapter(&chapter.name)
+                    .at_position(idx + 1)
+                    .at_row(word.row)
+                    .for_source(&word.source)
+            });
+        }
+    }
+
+    fn check_word(report: &mut ImportReport, word: &ImportWord) {
+        if word.source.trim().is_empty() {
+            report.push(ImportIssue::error("source", "Missing source".to_string()));
+        }
+        if word.translation.trim().is_empty() {
+            report.push(ImportIssue::error("translation", "Missing translation".to_string()));
+        }
+
+        let limits = [
+            ("source", Some(word.source.as_str()), MAX_SOURCE_LENGTH),
+            ("translation", Some(word.translation.as_str()), MAX_TRANSLATION_LENGTH),
+            ("phonetic", word.phonetic.as_deref(), MAX_PHONETIC_LENGTH),
+            ("part_of_speech", word.part_of_speech.as_deref(), MAX_PART_OF_SPEECH_LENGTH),
+        ];
+        for (field, value, max) in limits {
+            if value.is_some_and(|value| Self::too_long(value, max)) {
+                report.push(ImportIssue::error(
+                    field,
+                    format!("{} exceeds {} characters", field, max),
+                ));
+            }
+        }
+
+        if word.examples.len() > MAX_EXAMPLES {
+            report.push(ImportIssue::error(
+                "examples",
+                format!("More than {} examples", MAX_EXAMPLES),
+            ));
+        }
+        if word.examples.iter().any(|example| {
+            Self::too_long(&example.sentence, MAX_EXAMPLE_LENGTH)
+                || example
+                    .translation
+                    .as_deref()
+                    .is_some_and(|translation| Self::too_long(translation, MAX_EXAMPLE_LENGTH))
+        }) {
+            report.push(ImportIssue::error(
+                "examples",
+                format!("Example exceeds {} characters", MAX_EXAMPLE_LENGTH),
+            ));
+        }
+
+        if let Some(tag) = word.tags.iter().find(|tag| Self::too_long(tag.name.trim(), MAX_TAG_LENGTH)) {
+            report.push(ImportIssue::error(
+                "tags",
+                format!("Tag '{}' exceeds {} characters", tag.name, MAX_TAG_LENGTH),
+            ));
+        }
+        if let Some(tag) = word
+            .tags
+            .iter()
+            .find(|tag| tag.color.as_deref().is_some_and(|color| !ImportTag::is_color(color)))
+        {
+            report.push(ImportIssue::error(
+                "tags",
+                format!("Tag '{}' has an invalid color", tag.name),
+            ));
+        }
+    }
+
+    fn too_long(value: &str, max: usize) -> bool {
+        value.chars().count() > max
+    }
+}
+
+impl ImportReport {
+    pub fn error_summary(&self) -> Option<String> {
+        let first = self.errors.first()?;
+        Some(match self.errors.len() {
+            1 => first.to_string(),
+            count => format!("{} (and {} more errors)", first, count - 1),
+        })
+    }
+
+    pub fn merge(&mut self, other: ImportReport) {
+        self.extend(other, |issue| issue);
+    }
+
+    fn push(&mut self, issue: ImportIssue) {
+        match issue.severity {
+            IssueSeverity::Error => self.errors.push(issue),
+            IssueSeverity::Warning => self.warnings.push(issue),
+        }
+    }
+
+    fn extend(&mut self, other: ImportReport, locate: impl Fn(ImportIssue) -> ImportIssue) {
+        for issue in other.errors.into_iter().chain(other.warnings) {
+            self.push(locate(issue));
+        }
+    }
+}
+
+impl ImportIssue {
+    fn error(field: &'static str, message: String) -> Self {
+        Self::new(IssueSeverity::Error, field, message)
+    }
+
+    fn warning(field: &'static str, message: String) -> Self {
+        Self::new(IssueSeverity::Warning, field, message)
+    }
+
+    fn new(severity: IssueSeverity, field: &'static str, message: String) -> Self {
+        Self {
+            severity,
+            row: None,
+            chapter: None,
+            position: None,
+            source: None,
+            field,
+            message,
+        }
+    }
+
+    fn in_chapter(mut self, chapter: &str) -> Self {
+        self.chapter = Some(chapter.to_string());
+        self
+    }
+
+    fn at_position(mut self, position: usize) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    fn at_row(mut self, row: Option<usize>) -> Self {
+        self.row = row;
+        self
+    }
+
+    fn for_source(mut self, source: &str) -> Self {
+        self.source = Some(source.to_string()).filter(|source| !source.trim().is_empty());
+        self
+    }
+}
+
+impl fmt::Display for ImportIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.row, &self.chapter, self.position) {
+            (Some(row), _, _) => write!(f, "Row {}: {}", row, self.message),
+            (None, Some(chapter), Some(position)) => {
+                write!(f, "Chapter '{}', word {}: {}", chapter, position, self.message)
+            }
+            (None, Some(chapter), None) => write!(f, "Chapter '{}': {}", chapter, self.message),
+            (None, None, _) => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl WordLocation<'_> {
+    fn describe(&self, current: &WordLocation) -> String {
+        match self.row {
+            Some(row) => format!("row {}", row),
+            None if self.chapter == current.chapter => format!("word {}", self.position),
+            None => format!("word {} in chapter '{}'", self.position, self.chapter),
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,423 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+use sea_orm::ActiveModelTrait;
+use sea_orm::ActiveValue::NotSet;
+use sea_orm::ActiveValue::Unchanged;
+use sea_orm::ColumnTrait;
+use sea_orm::ConnectionTrait;
+use sea_orm::DatabaseConnection;
+use sea_orm::DbErr;
+use sea_orm::EntityTrait;
+use sea_orm::QueryFilter;
+use sea_orm::QueryOrder;
+use sea_orm::Set;
+use sea_orm::TransactionTrait;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::examples::ExampleStore;
+use crate::import::data::ImportChapter;
+use crate::import::data::ImportWord;
+use crate::import::data::ImportWordbook;
+use crate::tags::TagStore;
+
+const WORD_BATCH_SIZE: usize = 500;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DuplicateStrategy {
+    Skip,
+    Update,
+    #[default]
+    KeepBoth,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ImportTarget {
+    pub wordbook_id: i32,
+    pub chapter_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportResult {
+    pub chapters_created: usize,
+    pub words_created: usize,
+    pub words_updated: usize,
+    pub words_skipped: usize,
+}
+
+#[derive(Default)]
+struct MergePlan {
+    inserts: Vec<ImportWord>,
+    updates: Vec<(i32, ImportWord)>,
+    updated: usize,
+    skipped: usize,
+}
+
+pub struct ImportWriter;
+
+impl MergePlan {
+    fn count(&self, result: &mut ImportResult) {
+        result.words_created += self.inserts.len();
+        result.words_updated += self.updated;
+        result.words_skipped += self.skipped;
+    }
+}
+
+impl ImportWriter {
+    pub async fn save_wordbook(
+        db: &DatabaseConnection,
+        user_id: i32,
+        wordbook: ImportWordbook,
+        on_duplicate: DuplicateStrategy,
+        progress: &(impl Fn(usize) + Sync),
+    ) -> Result<ImportResult, DbErr> {
+        let txn = db.begin().await?;
+        let now = Utc::now().fixed_offset();
+
+        let max_order = entity::wordbooks::Entity::find()
+            .filter(entity::wordbooks::Column::UserId.eq(user_id))
+            .order_by_desc(entity::wordbooks::Column::SortOrder)
+            .one(&txn)
+            .await?
+            .map(|w| w.sort_order + 1)
+            .unwrap_or(0);
+
+        let saved_wb = entity::wordbooks::ActiveModel {
+            id: NotSet,
+            user_id: Set(user_id),
+            name: Set(wordbook.name),
+            description: Set(wordbook.description),
+            cover_url: Set(None),
+            sort_order: Set(max_order),
+            created_at: Set(now),
+            updated_at: Set(now),
+        }
+        .insert(&txn)
+        .await?;
+
+        let tag_ids = TagStore::resolve(
+            &txn,
+            user_id,
+            wordbook.chapters.iter().flat_map(|c| &c.words).flat_map(|w| &w.tags),
+        )
+        .await?;
+
+        let mut result = ImportResult::default();
+        let (names, words): (Vec<String>, Vec<Vec<ImportWord>>) = wordbook
+            .chapters
+            .into_iter()
+            .map(|chapter| (chapter.name, chapter.words))
+            .unzip();
+        let plans = Self::plan(words, &HashMap::new(), on_duplicate);
+        for (ch_idx, (name, plan)) in names.into_iter().zip(plans).enumerate() {
+            let chapter_id = Self::insert_chapter(&txn, saved_wb.id, name, ch_idx as i32).await?;
+            result.chapters_created += 1;
+            Self::apply(&txn, chapter_id, 0, plan, &tag_ids, &mut result, progress).await?;
+        }
+
+        txn.commit().await?;
+        Ok(result)
+    }
+
+    pub async fn save_chapter(
+        db: &DatabaseConnection,
+        user_id: i32,
+        target: ImportTarget,
+        chapter: ImportChapter,
+        on_duplicate: DuplicateStrategy,
+        progress: &(impl Fn(usize) + Sync),
+    ) -> Result<ImportResult, DbErr> {
+        let txn = db.begin().await?;
+        let mut result = ImportResult::default();
+
+        let tag_ids =
+            TagStore::resolve(&txn, user_id, chapter.words.iter().flat_map(|w| &w.tags)).await?;
+
+        let (chapter_id, start_order) = match target.chapter_id {
+            Some(chapter_id) => {
+                let next_order = entity::words::Entity::find()
+                    .filter(entity::words::Column::ChapterId.eq(chapter_id))
+                    .order_by_desc(entity::words::Column::SortOrder)
+                    .one(&txn)
+                    .await?
+                    .map(|w| w.sort_order + 1)
+                    .unwrap_or(0);
+                (chapter_id, next_order)
+            }
+            None => {
+                let max_order = entity::chapters::Entity::find()
+                    .filter(entity::chapters::Column::WordbookId.eq(target.wordbook_id))
+                    .order_by_desc(entity::chapters::Column::SortOrder)
+                    .one(&txn)
+                    .await?
+                    .map(|c| c.sort_order + 1)
+                    .unwrap_or(0);
+                result.chapters_created = 1;
+                let chapter_id =
+                    Self::insert_chapter(&txn, target.wordbook_id, chapter.name, max_order).await?;
+                (chapter_id, 0)
+            }
+        };
+
+        let existing = match on_duplicate {
+            DuplicateStrategy::KeepBoth => HashMap::new(),
+            _ => Self::existing_words(&txn, target.wordbook_id).await?,
+        };
+        for plan in Self::plan(vec![chapter.words], &existing, on_duplicate) {
+            Self::apply(&txn, chapter_id, start_order, plan, &tag_ids, &mut result, progress)
+                .await?;
+        }
+
+        txn.commit().await?;
+        Ok(result)
+    }
+
+    // Counts what a save would do without writing anything, by planning the merge
+    // against the same existing words.
+    pub fn preview_wordbook(wordbook: &ImportWordbook, on_duplicate: DuplicateStrategy) -> ImportResult {
+        let mut result = ImportResult {
+            chapters_created: wordbook.chapters.len(),
+            ..Default::default()
+        };
+        let words = wordbook.chapters.iter().map(|chapter| chapter.words.clone()).collect();
+        for plan in Self::plan(words, &HashMap::new(), on_duplicate) {
+            plan.count(&mut result);
+        }
+        result
+    }
+
+    pub async fn preview_chapter(
+        db: &DatabaseConnection,
+        target: ImportTarget,
+        chapter: &ImportChapter,
+        on_duplicate: DuplicateStrategy,
+    ) -> Result<ImportResult, DbErr> {
+        let mut result = ImportResult {
+            chapters_created: usize::from(target.chapter_id.is_none()),
+            ..Default::default()
+        };
+        let existing = match on_duplicate {
+            DuplicateStrategy::KeepBoth => HashMap::new(),
+            _ => Self::existing_words(db, target.wordbook_id).await?,
+        };
+        for plan in Self::plan(vec![chapter.words.clone()], &existing, on_duplicate) {
+            plan.count(&mut result);
+        }
+        Ok(result)
+    }
+
+    async fn existing_words<C: ConnectionTrait>(
+        db: &C,
+        wordbook_id: i32,
+    ) -> Result<HashMap<String, i32>, DbErr> {
+        let mut existing = HashMap::new();
+        for word in entity::words::Entity::find()
+            .inner_join(entity::chapters::Entity)
+            .filter(entity::chapters::Column::WordbookId.eq(wordbook_id))
+            .order_by_asc(entity::chapters::Column::SortOrder)
+            .order_by_asc(entity::words::Column::SortOrder)
+            .all(db)
+            .await?
+        {
+            existing
+                .entry(ImportWord::normalize_source(&word.source))
+                .or_insert(word.id);
+        }
+        Ok(existing)
+    }
+
+    // `existing` maps normalized sources to saved word ids. Every chapter is planned
+    // before anything is written, so a duplicate of a word queued in an earlier chapter
+    // replaces that queued insert.
+    fn plan(
+        chapters: Vec<Vec<ImportWord>>,
+        existing: &HashMap<String, i32>,
+        on_duplicate: DuplicateStrategy,
+    ) -> Vec<MergePlan> {
+        let mut plans: Vec<MergePlan> = chapters.iter().map(|_| MergePlan::default()).collect();
+        if on_duplicate == DuplicateStrategy::KeepBoth {
+            for (plan, words) in plans.iter_mut().zip(chapters) {
+                plan.inserts = words;
+            }
+            return plans;
+        }
+
+        // Queued inserts by normalized source, as (chapter, position in its inserts).
+        let mut pending: HashMap<String, (usize, usize)> = HashMap::new();
+        for (ch_idx, words) in chapters.into_iter().enumerate() {
+            for word in words {
+                let key = ImportWord::normalize_source(&word.source);
+                let duplicate = existing.get(&key).copied();
+                let queued = pending.get(&key).copied();
+                match (duplicate, queued, on_duplicate) {
+                    (None, None, _) => {
+                        pending.insert(key, (ch_idx, plans[ch_idx].inserts.len()));
+                        plans[ch_idx].inserts.push(word);
+                    }
+                    (_, _, DuplicateStrategy::Skip) => plans[ch_idx].skipped += 1,
+                    (Some(word_id), _, _) => {
+                        plans[ch_idx].updates.push((word_id, word));
+                        plans[ch_idx].updated += 1;
+                    }
+                    (None, Some((queued_ch, idx)), _) => {
+                        plans[queued_ch].inserts[idx] = word;
+                        plans[ch_idx].updated += 1;
+                    }
+                }
+            }
+        }
+        plans
+    }
+
+    async fn apply<C: ConnectionTrait>(
+        db: &C,
+        chapter_id: i32,
+        start_order: i32,
+        plan: MergePlan,
+        tag_ids: &HashMap<String, i32>,
+        result: &mut ImportResult,
+        progress: &(impl Fn(usize) + Sync),
+    ) -> Result<(), DbErr> {
+        result.words_created +=
+            Self::insert_words(db, chapter_id, start_order, plan.inserts, tag_ids, progress).await?;
+
+        // Words merged into another row of the same file count as processed with the updates.
+        progress(plan.updated - plan.updates.len() + plan.skipped);
+        for (word_id, word) in plan.updates {
+            Self::update_word(db, word_id, word, tag_ids).await?;
+            progress(1);
+        }
+        result.words_updated += plan.updated;
+        result.words_skipped += plan.skipped;
+        Ok(())
+    }
+
+    async fn insert_chapter<C: ConnectionTrait>(
+        db: &C,
+        wordbook_id: i32,
+        name: String,
+        sort_order: i32,
+    ) -> Result<i32, DbErr> {
+        let now = Utc::now().fixed_offset();
+        let chapter = entity::chapters::ActiveModel {
+            id: NotSet,
+            wordbook_id: Set(wordbook_id),
+            name: Set(name),
+            sort_order: Set(sort_order),
+            created_at: Set(now),
+            updated_at: Set(now),
+        }
+        .insert(db)
+        .await?;
+        Ok(chapter.id)
+    }
+
+    async fn insert_words<C: ConnectionTrait>(
+        db: &C,
+        chapter_id: i32,
+        start_order: i32,
+        words: Vec<ImportWord>,
+        tag_ids: &HashMap<String, i32>,
+        progress: &(impl Fn(usize) + Sync),
+    ) -> Result<usize, DbErr> {
+        let now = Utc::now().fixed_offset();
+        let total = words.len();
+        let mut remaining = words.into_iter().enumerate();
+
+        loop {
+            let batch: Vec<(i32, ImportWord)> = remaining
+                .by_ref()
+                .take(WORD_BATCH_SIZE)
+                .map(|(idx, word)| (start_order + idx as i32, word))
+                .collect();
+            if batch.is_empty() {
+                break;
+            }
+
+            let models = batch.iter().map(|(sort_order, word)| entity::words::ActiveModel {
+                id: NotSet,
+                chapter_id: Set(chapter_id),
+                source: Set(word.source.clone()),
+                translation: Set(word.translation.clone()),
+                note: Set(word.note.clone()),
+                sort_order: Set(*sort_order),
+                created_at: Set(now),
+                updated_at: Set(now),
+                phonetic: Set(word.phonetic.clone()),
+                part_of_speech: Set(word.part_of_speech.clone()),
+            });
+
+            let word_ids: HashMap<i32, i32> = entity::words::Entity::insert_many(models)
+                .exec_with_returning_many(db)
+                .await?
+                .into_iter()
+                .map(|w| (w.sort_order, w.id))
+                .collect();
+            let word_id = |sort_order: &i32| {
+                word_ids
+                    .get(sort_order)
+                    .copied()
+                    .ok_or(DbErr::RecordNotInserted)
+            };
+
+            let mut examples = Vec::new();
+            let mut tags = Vec::new();
+            for (sort_order, word) in &batch {
+                let id = word_id(sort_order)?;
+                tags.push((id, word.tags.as_slice()));
+                if !word.examples.is_empty() {
+                    examples.push((id, word.examples.clone()));
+                }
+            }
+
+            ExampleStore::insert_many(db, examples).await?;
+            TagStore::attach(db, tags, tag_ids).await?;
+            progress(batch.len());
+        }
+
+        Ok(total)
+    }
+
+    async fn update_word<C: ConnectionTrait>(
+        db: &C,
+        word_id: i32,
+        word: ImportWord,
+        tag_ids: &HashMap<String, i32>,
+    ) -> Result<(), DbErr> {
+        let mut active = entity::words::ActiveModel {
+            id: Unchanged(word_id),
+            updated_at: Set(Utc::now().fixed_offset()),
+            ..Default::default()
+        };
+        if !word.translation.trim().is_empty() {
+            active.translation = Set(word.translation);
+        }
+        if word.note.is_some() {
+            active.note = Set(word.note);
+        }
+        if word.phonetic.is_some() {
+            active.phonetic = Set(word.phonetic);
+        }
+        if word.part_of_speech.is_some() {
+            active.part_of_speech = Set(word.part_of_speech);
+        }
+        active.update(db).await?;
+
+        if !word.examples.is_empty() {
+            ExampleStore::replace(db, word_id, word.examples).await?;
+        }
+        TagStore::attach(db, [(word_id, word.tags.as_slice())], tag_ids).await
+    }
+}
+
+impl DuplicateStrategy {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "skip" => Some(Self::Skip),
+            "update" => Some(Self::Update),
+            "keep_both" => Some(Self::KeepBoth),
+            _ => None,
+        }
+    }
+}
\ No newline at end of file
@@ -1,12 +1,27 @@
 mod auth;
+mod backup;
 mod config;
 mod db;
+mod dictionary;
 mod error;
+mod examples;
+mod export;
+mod filter;
 mod handlers;
 mod import;
+mod markup;
+mod quiz;
+mod review;
 mod routes;
+mod scope;
+mod search;
+mod shuffle;
 mod state;
 mod static_files;
+mod stats;
+mod tags;
+mod time;
+mod worksheet;
 
 use std::net::SocketAddr;
 
@@ -25,9 +40,12 @@ use tracing_subscriber::util::SubscriberInitExt;
 
 use crate::config::Config;
 use crate::db::DbPool;
+use crate::dictionary::loader::DictionaryLoader;
+use crate::import::jobs::ImportJobs;
 use crate::routes::AppRouter;
 use crate::state::AppState;
 use crate::static_files::StaticFiles;
+use crate::worksheet::fonts::WorksheetFonts;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -44,6 +62,21 @@ async fn main() -> anyhow::Result<()> {
     let db = DbPool::connect(&config).await?;
     migration::Migrator::up(&db, None).await?;
 
+    let interrupted = ImportJobs::fail_interrupted(&db).await?;
+    if interrupted > 0 {
+        tracing::warn!("Marked {} interrupted import jobs as failed", interrupted);
+    }
+
+    if let Some(path) = config.dictionary_path.clone() {
+        let db = db.clone();
+        tokio::spawn(async move {
+            match DictionaryLoader::load_if_empty(&db, &path).await {
+                Ok(count) => tracing::info!("Dictionary ready with {} entries", count),
+                Err(e) => tracing::error!("Failed to load dictionary from {}: {}", path, e),
+            }
+        });
+    }
+
     let session_pool = sqlx::SqlitePool::connect(&config.database_url).await?;
     let session_store = SqliteStore::new(session_pool);
     session_store.migrate().await?;
@@ -52,7 +85,8 @@ async fn main() -> anyhow::Result<()> {
         .with_secure(false)
         .with_expiry(Expiry::OnInactivity(Duration::days(7)));
 
-    let state = AppState::new(db);
+    let worksheet_fonts = WorksheetFonts::load(&config.pdf_font_paths);
+    let state = AppState::new(db, worksheet_fonts);
     let api_routes = AppRouter::create(state);
 
     let cors = CorsLayer::new()
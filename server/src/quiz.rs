@@ -0,0 +1,2 @@
+pub mod answer;
+pub mod builder;
\ No newline at end of file
@@ -0,0 +1,28 @@
+const ALTERNATIVE_SEPARATORS: [char; 5] = [';', '；', ',', '，', '/'];
+
+pub struct AnswerMatcher;
+
+impl AnswerMatcher {
+    pub fn normalize(value: &str) -> String {
+        value
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+            .collect::<String>()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase()
+    }
+
+    pub fn is_correct(expected: &str, answer: &str) -> bool {
+        let answer = Self::normalize(answer);
+        if answer.is_empty() {
+            return false;
+        }
+
+        Self::normalize(expected) == answer
+            || expected
+                .split(ALTERNATIVE_SEPARATORS)
+                .any(|alternative| Self::normalize(alternative) == answer)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,111 @@
+use std::collections::HashSet;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::quiz::answer::AnswerMatcher;
+use crate::shuffle::Shuffler;
+
+const CHOICE_COUNT: usize = 4;
+const BLANK: char = '_';
+const MODE_MULTIPLE_CHOICE: &str = "multiple_choice";
+const MODE_TYPING: &str = "typing";
+const MODE_DICTATION: &str = "dictation";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuizMode {
+    MultipleChoice,
+    Typing,
+    Dictation,
+}
+
+impl QuizMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QuizMode::MultipleChoice => MODE_MULTIPLE_CHOICE,
+            QuizMode::Typing => MODE_TYPING,
+            QuizMode::Dictation => MODE_DICTATION,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct QuestionDraft {
+    pub word_id: i32,
+    pub prompt: String,
+    pub options: Option<Vec<String>>,
+    pub expected_answer: String,
+}
+
+pub struct QuestionBuilder;
+
+impl QuestionBuilder {
+    pub fn build(
+        mode: QuizMode,
+        words: &[entity::words::Model],
+        pool: &[entity::words::Model],
+        shuffler: &Shuffler,
+    ) -> Vec<QuestionDraft> {
+        words
+            .iter()
+            .map(|word| match mode {
+                QuizMode::MultipleChoice => {
+                    let mut options = Self::distractors(word, pool, shuffler);
+                    options.push(word.translation.clone());
+                    shuffler.shuffle_by(&mut options, |option| (word.id, option.clone()));
+
+                    QuestionDraft {
+                        word_id: word.id,
+                        prompt: word.source.clone(),
+                        options: Some(options),
+                        expected_answer: word.translation.clone(),
+                    }
+                }
+                QuizMode::Typing => QuestionDraft {
+                    word_id: word.id,
+                    prompt: word.translation.clone(),
+                    options: None,
+                    expected_answer: word.source.clone(),
+                },
+                QuizMode::Dictation => QuestionDraft {
+                    word_id: word.id,
+                    prompt: Self::dictation_prompt(word),
+                    options: None,
+                    expected_answer: word.source.clone(),
+                },
+            })
+            .collect()
+    }
+
+    // The word itself is only spoken, so the displayed prompt is the translation with
+    // one blank per letter as a hint: "ice cream" becomes "冰淇淋 (___ _____)".
+    fn dictation_prompt(word: &entity::words::Model) -> String {
+        let blanks: String = word
+            .source
+            .trim()
+            .chars()
+            .map(|c| if c.is_alphanumeric() { BLANK } else { c })
+            .collect();
+        format!("{} ({})", word.translation, blanks)
+    }
+
+    fn distractors(
+        word: &entity::words::Model,
+        pool: &[entity::words::Model],
+        shuffler: &Shuffler,
+    ) -> Vec<String> {
+        let mut candidates: Vec<&entity::words::Model> =
+            pool.iter().filter(|w| w.id != word.id).collect();
+        shuffler.shuffle_by(&mut candidates, |w| (word.id, w.id));
+        candidates.sort_by_key(|w| w.chapter_id != word.chapter_id);
+
+        let mut seen = HashSet::from([AnswerMatcher::normalize(&word.translation)]);
+        candidates
+            .into_iter()
+            .filter(|w| seen.insert(AnswerMatcher::normalize(&w.translation)))
+            .map(|w| w.translation.clone())
+            .take(CHOICE_COUNT - 1)
+            .collect()
+    }
+}
\ No newline at end of file
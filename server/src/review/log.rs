@@ -0,0 +1,47 @@
+use chrono::DateTime;
+use chrono::FixedOffset;
+use sea_orm::ActiveValue::NotSet;
+use sea_orm::ConnectionTrait;
+use sea_orm::DbErr;
+use sea_orm::EntityTrait;
+use sea_orm::Set;
+
+pub const ACTIVITY_REVIEW: &str = "review";
+pub const ACTIVITY_QUIZ: &str = "quiz";
+
+pub struct ReviewLogEntry {
+    pub word_id: i32,
+    pub grade: Option<u8>,
+    pub is_correct: bool,
+}
+
+pub struct ReviewLogger;
+
+impl ReviewLogger {
+    pub async fn record<C: ConnectionTrait>(
+        db: &C,
+        user_id: i32,
+        activity: &str,
+        entries: Vec<ReviewLogEntry>,
+        reviewed_at: DateTime<FixedOffset>,
+    ) -> Result<(), DbErr> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        let logs = entries
+            .into_iter()
+            .map(|entry| entity::review_logs::ActiveModel {
+                id: NotSet,
+                user_id: Set(user_id),
+                word_id: Set(Some(entry.word_id)),
+                activity: Set(activity.to_string()),
+                grade: Set(entry.grade.map(i32::from)),
+                is_correct: Set(entry.is_correct),
+                reviewed_at: Set(reviewed_at),
+            });
+
+        entity::review_logs::Entity::insert_many(logs).exec(db).await?;
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,84 @@
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::FixedOffset;
+
+pub const INITIAL_EASE_FACTOR: f64 = 2.5;
+pub const MAX_GRADE: u8 = 5;
+const MIN_EASE_FACTOR: f64 = 1.3;
+const PASSING_GRADE: u8 = 3;
+const FIRST_INTERVAL_DAYS: i32 = 1;
+const SECOND_INTERVAL_DAYS: i32 = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReviewSchedule {
+    pub ease_factor: f64,
+    pub interval_days: i32,
+    pub repetitions: i32,
+    pub lapses: i32,
+}
+
+impl Default for ReviewSchedule {
+    fn default() -> Self {
+        Self {
+            ease_factor: INITIAL_EASE_FACTOR,
+            interval_days: 0,
+            repetitions: 0,
+            lapses: 0,
+        }
+    }
+}
+
+impl From<&entity::review_states::Model> for ReviewSchedule {
+    fn from(state: &entity::review_states::Model) -> Self {
+        Self {
+            ease_factor: state.ease_factor,
+            interval_days: state.interval_days,
+            repetitions: state.repetitions,
+            lapses: state.lapses,
+        }
+    }
+}
+
+pub struct Sm2Scheduler;
+
+impl Sm2Scheduler {
+    pub fn is_passing(grade: u8) -> bool {
+        grade >= PASSING_GRADE
+    }
+
+    pub fn schedule(current: ReviewSchedule, grade: u8) -> ReviewSchedule {
+        let grade = grade.min(MAX_GRADE);
+        let penalty = f64::from(MAX_GRADE - grade);
+        let ease_factor = (current.ease_factor + 0.1 - penalty * (0.08 + penalty * 0.02))
+            .max(MIN_EASE_FACTOR);
+
+        if !Self::is_passing(grade) {
+            return ReviewSchedule {
+                ease_factor,
+                interval_days: FIRST_INTERVAL_DAYS,
+                repetitions: 0,
+                lapses: current.lapses + 1,
+            };
+        }
+
+        let interval_days = match current.repetitions {
+            0 => FIRST_INTERVAL_DAYS,
+            1 => SECOND_INTERVAL_DAYS,
+            _ => (f64::from(current.interval_days) * current.ease_factor).round() as i32,
+        };
+
+        ReviewSchedule {
+            ease_factor,
+            interval_days,
+            repetitions: current.repetitions + 1,
+            lapses: current.lapses,
+        }
+    }
+
+    pub fn due_at(
+        reviewed_at: DateTime<FixedOffset>,
+        schedule: &ReviewSchedule,
+    ) -> DateTime<FixedOffset> {
+        reviewed_at + Duration::days(i64::from(schedule.interval_days))
+    }
+}
\ No newline at end of file
@@ -4,10 +4,16 @@ use axum::routing::post;
 use axum::routing::put;
 use axum::Router;
 
+use crate::handlers::account_handler::AccountHandler;
 use crate::handlers::auth_handler::AuthHandler;
 use crate::handlers::chapter_handler::ChapterHandler;
+use crate::handlers::dictionary_handler::DictionaryHandler;
 use crate::handlers::export_handler::ExportHandler;
 use crate::handlers::import_handler::ImportHandler;
+use crate::handlers::quiz_handler::QuizHandler;
+use crate::handlers::review_handler::ReviewHandler;
+use crate::handlers::search_handler::SearchHandler;
+use crate::handlers::stats_handler::StatsHandler;
 use crate::handlers::tag_handler::TagHandler;
 use crate::handlers::word_handler::WordHandler;
 use crate::handlers::wordbook_handler::WordbookHandler;
@@ -23,6 +29,10 @@ impl AppRouter {
             .route("/logout", post(AuthHandler::logout))
             .route("/me", get(AuthHandler::me));
 
+        let account_routes = Router::new()
+            .route("/export", get(AccountHandler::export))
+            .route("/import", post(AccountHandler::import));
+
         let tag_routes = Router::new()
             .route("/", get(TagHandler::list).post(TagHandler::create))
             .route("/{id}", put(TagHandler::update).delete(TagHandler::delete));
@@ -57,6 +67,9 @@ impl AppRouter {
         let import_routes = Router::new()
             .route("/templates", get(ImportHandler::download_template))
             .route("/wordbooks", post(ImportHandler::import_wordbook))
+            .route("/jobs", get(ImportHandler::list_jobs))
+            .route("/jobs/{id}", get(ImportHandler::get_job))
+            .route("/jobs/{id}/events", get(ImportHandler::job_events))
             .route(
                 "/wordbooks/{wordbook_id}/chapters",
                 post(ImportHandler::import_chapter),
@@ -69,13 +82,35 @@ impl AppRouter {
                 get(ExportHandler::export_chapter),
             );
 
+        let review_routes = Router::new()
+            .route("/due", get(ReviewHandler::due))
+            .route("/{word_id}/grade", post(ReviewHandler::grade));
+
+        let quiz_routes = Router::new()
+            .route("/", get(QuizHandler::list).post(QuizHandler::create))
+            .route("/{id}", get(QuizHandler::get))
+            .route("/{id}/submit", post(QuizHandler::submit));
+
+        let stats_routes = Router::new()
+            .route("/daily", get(StatsHandler::daily))
+            .route("/accuracy", get(StatsHandler::accuracy))
+            .route("/streak", get(StatsHandler::streak))
+            .route("/retention", get(StatsHandler::retention))
+            .route("/heatmap", get(StatsHandler::heatmap));
+
         Router::new()
             .nest("/api/auth", auth_routes)
+            .nest("/api/account", account_routes)
             .nest("/api/tags", tag_routes)
             .nest("/api/wordbooks", wordbook_routes)
             .nest("/api", word_routes)
             .nest("/api/import", import_routes)
             .nest("/api/export", export_routes)
+            .nest("/api/review", review_routes)
+            .nest("/api/quizzes", quiz_routes)
+            .nest("/api/stats", stats_routes)
+            .route("/api/search", get(SearchHandler::search))
+            .route("/api/dictionary/lookup", get(DictionaryHandler::lookup))
             .with_state(state)
     }
 }
\ No newline at end of file
@@ -0,0 +1,80 @@
+use sea_orm::ColumnTrait;
+use sea_orm::DatabaseConnection;
+use sea_orm::EntityTrait;
+use sea_orm::QueryFilter;
+use sea_orm::QueryOrder;
+
+use crate::error::AppError;
+
+pub struct WordScope;
+
+impl WordScope {
+    pub async fn chapter_ids(
+        db: &DatabaseConnection,
+        user_id: i32,
+        wordbook_id: Option<i32>,
+        chapter_id: Option<i32>,
+    ) -> Result<Vec<i32>, AppError> {
+        let mut wordbooks =
+            entity::wordbooks::Entity::find().filter(entity::wordbooks::Column::UserId.eq(user_id));
+        if let Some(wordbook_id) = wordbook_id {
+            wordbooks = wordbooks.filter(entity::wordbooks::Column::Id.eq(wordbook_id));
+        }
+
+        let wordbook_ids: Vec<i32> = wordbooks
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|wb| wb.id)
+            .collect();
+
+        if wordbook_ids.is_empty() {
+            return match wordbook_id {
+                Some(_) => Err(AppError::NotFound("Wordbook not found".to_string())),
+                None => Ok(vec![]),
+            };
+        }
+
+        let mut chapters = entity::chapters::Entity::find()
+            .filter(entity::chapters::Column::WordbookId.is_in(wordbook_ids));
+        if let Some(chapter_id) = chapter_id {
+            chapters = chapters.filter(entity::chapters::Column::Id.eq(chapter_id));
+        }
+
+        let chapter_ids: Vec<i32> = chapters
+            .order_by_asc(entity::chapters::Column::SortOrder)
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|ch| ch.id)
+            .collect();
+
+        if chapter_id.is_some() && chapter_ids.is_empty() {
+            return Err(AppError::NotFound("Chapter not found".to_string()));
+        }
+
+        Ok(chapter_ids)
+    }
+
+    pub async fn find_word(
+        db: &DatabaseConnection,
+        user_id: i32,
+        word_id: i32,
+    ) -> Result<entity::words::Model, AppError> {
+        let (word, chapter) = entity::words::Entity::find_by_id(word_id)
+            .find_also_related(entity::chapters::Entity)
+            .one(db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Word not found".to_string()))?;
+
+        let chapter = chapter.ok_or_else(|| AppError::NotFound("Word not found".to_string()))?;
+
+        entity::wordbooks::Entity::find_by_id(chapter.wordbook_id)
+            .filter(entity::wordbooks::Column::UserId.eq(user_id))
+            .one(db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Word not found".to_string()))?;
+
+        Ok(word)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,154 @@
+use crate::markup::Markup;
+
+const TRIGRAM_LENGTH: usize = 3;
+const MARK_OPEN: &str = "<mark>";
+const MARK_CLOSE: &str = "</mark>";
+const SNIPPET_CONTEXT_CHARS: usize = 30;
+const ELLIPSIS: &str = "…";
+
+pub struct SearchTerms {
+    pub terms: Vec<String>,
+}
+
+impl SearchTerms {
+    pub fn parse(query: &str) -> Self {
+        let terms = query
+            .split_whitespace()
+            .map(|term| {
+                term.trim_end_matches('*')
+                    .chars()
+                    .filter(|c| *c != '"')
+                    .collect::<String>()
+            })
+            .filter(|term| !term.is_empty())
+            .collect();
+        Self { terms }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn match_expression(&self) -> Option<String> {
+        let phrases: Vec<String> = self
+            .terms
+            .iter()
+            .filter(|term| term.chars().count() >= TRIGRAM_LENGTH)
+            .map(|term| format!("\"{}\"", term))
+            .collect();
+
+        (!phrases.is_empty()).then(|| phrases.join(" AND "))
+    }
+
+    pub fn like_patterns(&self) -> Vec<String> {
+        self.terms
+            .iter()
+            .filter(|term| term.chars().count() < TRIGRAM_LENGTH)
+            .map(|term| format!("%{}%", Self::escape_like(term)))
+            .collect()
+    }
+
+    pub fn prefix_pattern(&self) -> String {
+        format!(
+            "{}%",
+            Self::escape_like(self.terms.first().map(String::as_str).unwrap_or(""))
+        )
+    }
+
+    pub fn escape_like(term: &str) -> String {
+        term.replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_")
+    }
+
+    // The result is HTML: word text is escaped, with line breaks as <br>.
+    pub fn highlight(&self, text: &str) -> String {
+        let ranges = self.match_ranges(text);
+        let mut result = String::with_capacity(text.len());
+        let mut cursor = 0;
+        for (start, end) in ranges {
+            result.push_str(&Markup::escape(&text[cursor..start]));
+            result.push_str(MARK_OPEN);
+            result.push_str(&Markup::escape(&text[start..end]));
+            result.push_str(MARK_CLOSE);
+            cursor = end;
+        }
+        result.push_str(&Markup::escape(&text[cursor..]));
+        result
+    }
+
+    pub fn snippet(&self, text: &str) -> String {
+        let Some((first, _)) = self.match_ranges(text).into_iter().next() else {
+            let truncated: String = text.chars().take(SNIPPET_CONTEXT_CHARS * 2).collect();
+            return if truncated.len() < text.len() {
+                format!("{}{}", Markup::escape(&truncated), ELLIPSIS)
+            } else {
+                Markup::escape(&truncated)
+            };
+        };
+
+        let start = text[..first]
+            .char_indices()
+            .rev()
+            .nth(SNIPPET_CONTEXT_CHARS - 1)
+            .map(|(idx, _)| idx)
+            .unwrap_or(0);
+        let end = text[first..]
+            .char_indices()
+            .nth(SNIPPET_CONTEXT_CHARS * 2)
+            .map(|(idx, _)| first + idx)
+            .unwrap_or(text.len());
+
+        let mut snippet = String::new();
+        if start > 0 {
+            snippet.push_str(ELLIPSIS);
+        }
+        snippet.push_str(&self.highlight(&text[start..end]));
+        if end < text.len() {
+            snippet.push_str(ELLIPSIS);
+        }
+        snippet
+    }
+
+    fn match_ranges(&self, text: &str) -> Vec<(usize, usize)> {
+        let lower: Vec<(usize, char)> = text
+            .char_indices()
+            .map(|(idx, c)| (idx, c.to_lowercase().next().unwrap_or(c)))
+            .collect();
+
+        let mut ranges: Vec<(usize, usize)> = Vec::new();
+        for term in &self.terms {
+            let needle: Vec<char> = term
+                .chars()
+                .map(|c| c.to_lowercase().next().unwrap_or(c))
+                .collect();
+            if needle.is_empty() || needle.len() > lower.len() {
+                continue;
+            }
+            for pos in 0..=lower.len() - needle.len() {
+                if lower[pos..pos + needle.len()]
+                    .iter()
+                    .map(|(_, c)| *c)
+                    .eq(needle.iter().copied())
+                {
+                    let start = lower[pos].0;
+                    let end = lower
+                        .get(pos + needle.len())
+                        .map(|(idx, _)| *idx)
+                        .unwrap_or(text.len());
+                    ranges.push((start, end));
+                }
+            }
+        }
+
+        ranges.sort();
+        let mut merged: Vec<(usize, usize)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        merged
+    }
+}
\ No newline at end of file
@@ -0,0 +1,33 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use chrono::Utc;
+
+pub struct Shuffler {
+    seed: i64,
+}
+
+impl Default for Shuffler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Shuffler {
+    pub fn new() -> Self {
+        Self {
+            seed: Utc::now().timestamp_millis(),
+        }
+    }
+
+    pub fn shuffle_by<T, K: Hash>(&self, items: &mut [T], key: impl Fn(&T) -> K) {
+        items.sort_by_cached_key(|item| self.rank(&key(item)));
+    }
+
+    fn rank<K: Hash>(&self, key: &K) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        (key, self.seed).hash(&mut hasher);
+        hasher.finish()
+    }
+}
\ No newline at end of file
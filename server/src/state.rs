@@ -1,13 +1,22 @@
 use sea_orm::DatabaseConnection;
 use std::sync::Arc;
 
+use crate::import::jobs::ImportJobs;
+use crate::worksheet::fonts::WorksheetFonts;
+
 #[derive(Clone)]
 pub struct AppState {
     pub db: Arc<DatabaseConnection>,
+    pub import_jobs: ImportJobs,
+    pub worksheet_fonts: WorksheetFonts,
 }
 
 impl AppState {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db: Arc::new(db) }
+    pub fn new(db: DatabaseConnection, worksheet_fonts: WorksheetFonts) -> Self {
+        Self {
+            db: Arc::new(db),
+            import_jobs: ImportJobs::default(),
+            worksheet_fonts,
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,270 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use chrono::DateTime;
+use chrono::Days;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::NaiveTime;
+use chrono::TimeDelta;
+use serde::Serialize;
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const RETENTION_BUCKETS: [(i64, Option<i64>); 8] = [
+    (0, Some(0)),
+    (1, Some(1)),
+    (2, Some(2)),
+    (3, Some(6)),
+    (7, Some(13)),
+    (14, Some(29)),
+    (30, Some(59)),
+    (60, None),
+];
+
+pub struct LogPoint {
+    pub word_id: Option<i32>,
+    pub is_correct: bool,
+    pub reviewed_at: DateTime<FixedOffset>,
+}
+
+impl From<entity::review_logs::Model> for LogPoint {
+    fn from(log: entity::review_logs::Model) -> Self {
+        Self {
+            word_id: log.word_id,
+            is_correct: log.is_correct,
+            reviewed_at: log.reviewed_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DailyStat {
+    pub date: String,
+    pub learned: usize,
+    pub reviews: usize,
+    pub correct: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StreakStat {
+    pub current: usize,
+    pub longest: usize,
+    pub last_active_date: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RetentionPoint {
+    pub min_days: i64,
+    pub max_days: Option<i64>,
+    pub total: usize,
+    pub retained: usize,
+    pub rate: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HeatmapCell {
+    pub date: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AccuracyCount {
+    pub total: usize,
+    pub correct: usize,
+}
+
+impl AccuracyCount {
+    pub fn rate(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.correct as f64 / self.total as f64
+        }
+    }
+}
+
+pub struct StatsCalculator {
+    offset: FixedOffset,
+}
+
+impl StatsCalculator {
+    pub fn new(offset: FixedOffset) -> Self {
+        Self { offset }
+    }
+
+    pub fn local_date(&self, at: DateTime<FixedOffset>) -> NaiveDate {
+        at.with_timezone(&self.offset).date_naive()
+    }
+
+    pub fn format_date(date: NaiveDate) -> String {
+        date.format(DATE_FORMAT).to_string()
+    }
+
+    // Stored times may carry any offset and are compared as text, so reads start a day
+    // early; the local-date checks below drop the extra rows.
+    pub fn read_from(&self, from: NaiveDate) -> DateTime<FixedOffset> {
+        let start = from.pred_opt().unwrap_or(from).and_time(NaiveTime::MIN);
+        (start - TimeDelta::seconds(i64::from(self.offset.local_minus_utc())))
+            .and_utc()
+            .fixed_offset()
+    }
+
+    // SQLite date() modifier turning a stored time into a local date.
+    pub fn date_modifier(&self) -> String {
+        format!("{:+} seconds", self.offset.local_minus_utc())
+    }
+
+    // `first_reviews` holds each word's first review time; it marks the day it was learned.
+    pub fn daily(
+        &self,
+        logs: &[LogPoint],
+        first_reviews: &[DateTime<FixedOffset>],
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Vec<DailyStat> {
+        let mut days: BTreeMap<NaiveDate, DailyStat> = from
+            .iter_days()
+            .take_while(|date| *date <= to)
+            .map(|date| {
+                (
+                    date,
+                    DailyStat {
+                        date: Self::format_date(date),
+                        learned: 0,
+                        reviews: 0,
+                        correct: 0,
+                    },
+                )
+            })
+            .collect();
+
+        for log in logs {
+            if let Some(day) = days.get_mut(&self.local_date(log.reviewed_at)) {
+                day.reviews += 1;
+                if log.is_correct {
+                    day.correct += 1;
+                }
+            }
+        }
+
+        for first_review in first_reviews {
+            if let Some(day) = days.get_mut(&self.local_date(*first_review)) {
+                day.learned += 1;
+            }
+        }
+
+        days.into_values().collect()
+    }
+
+    // `active` holds the local dates with at least one review.
+    pub fn streak(active: &BTreeSet<NaiveDate>, today: NaiveDate) -> StreakStat {
+        let mut longest = 0;
+        let mut run = 0;
+        let mut previous: Option<NaiveDate> = None;
+        for date in active {
+            run = match previous {
+                Some(prev) if prev.checked_add_days(Days::new(1)) == Some(*date) => run + 1,
+                _ => 1,
+            };
+            longest = longest.max(run);
+            previous = Some(*date);
+        }
+
+        let mut current = 0;
+        let mut cursor = if active.contains(&today) {
+            Some(today)
+        } else {
+            today.pred_opt()
+        };
+        while let Some(date) = cursor.filter(|d| active.contains(d)) {
+            current += 1;
+            cursor = date.pred_opt();
+        }
+
+        StreakStat {
+            current,
+            longest,
+            last_active_date: active.last().map(|d| Self::format_date(*d)),
+        }
+    }
+
+    pub fn retention(&self, logs: &[LogPoint]) -> Vec<RetentionPoint> {
+        let mut by_word: HashMap<i32, Vec<&LogPoint>> = HashMap::new();
+        for log in logs {
+            if let Some(word_id) = log.word_id {
+                by_word.entry(word_id).or_default().push(log);
+            }
+        }
+
+        let mut counts = [AccuracyCount::default(); RETENTION_BUCKETS.len()];
+        for mut history in by_word.into_values() {
+            history.sort_by_key(|log| log.reviewed_at);
+            for pair in history.windows(2) {
+                let elapsed = (self.local_date(pair[1].reviewed_at)
+                    - self.local_date(pair[0].reviewed_at))
+                .num_days();
+                let bucket = RETENTION_BUCKETS
+                    .iter()
+                    .position(|(min, max)| elapsed >= *min && max.is_none_or(|max| elapsed <= max));
+                if let Some(idx) = bucket {
+                    counts[idx].total += 1;
+                    if pair[1].is_correct {
+                        counts[idx].correct += 1;
+                    }
+                }
+            }
+        }
+
+        RETENTION_BUCKETS
+            .iter()
+            .zip(counts)
+            .map(|((min_days, max_days), count)| RetentionPoint {
+                min_days: *min_days,
+                max_days: *max_days,
+                total: count.total,
+                retained: count.correct,
+                rate: count.rate(),
+            })
+            .collect()
+    }
+
+    pub fn heatmap(&self, logs: &[LogPoint], from: NaiveDate, to: NaiveDate) -> Vec<HeatmapCell> {
+        let mut counts: BTreeMap<NaiveDate, usize> = BTreeMap::new();
+        for log in logs {
+            let date = self.local_date(log.reviewed_at);
+            if date >= from && date <= to {
+                *counts.entry(date).or_default() += 1;
+            }
+        }
+
+        counts
+            .into_iter()
+            .map(|(date, count)| HeatmapCell {
+                date: Self::format_date(date),
+                count,
+            })
+            .collect()
+    }
+
+    pub fn accuracy_by<K: Eq + Hash>(
+        logs: &[LogPoint],
+        keys: impl Fn(i32) -> Vec<K>,
+    ) -> HashMap<K, AccuracyCount> {
+        let mut counts: HashMap<K, AccuracyCount> = HashMap::new();
+        for log in logs {
+            let Some(word_id) = log.word_id else {
+                continue;
+            };
+            for key in keys(word_id) {
+                let count = counts.entry(key).or_default();
+                count.total += 1;
+                if log.is_correct {
+                    count.correct += 1;
+                }
+            }
+        }
+        counts
+    }
+}
\ No newline at end of file
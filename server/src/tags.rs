@@ -0,0 +1,109 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use chrono::Utc;
+use sea_orm::ActiveModelTrait;
+use sea_orm::ActiveValue::NotSet;
+use sea_orm::ColumnTrait;
+use sea_orm::ConnectionTrait;
+use sea_orm::DbErr;
+use sea_orm::EntityTrait;
+use sea_orm::QueryFilter;
+use sea_orm::Set;
+use sea_orm::sea_query::OnConflict;
+
+use crate::import::data::ImportTag;
+
+const INSERT_BATCH_SIZE: usize = 500;
+
+pub struct TagStore;
+
+impl TagStore {
+    // Existing tags keep their color; missing ones are created with the first color given.
+    pub async fn resolve<'a, C: ConnectionTrait>(
+        db: &C,
+        user_id: i32,
+        tags: impl IntoIterator<Item = &'a ImportTag>,
+    ) -> Result<HashMap<String, i32>, DbErr> {
+        let mut colors: BTreeMap<String, Option<String>> = BTreeMap::new();
+        for tag in tags {
+            let name = tag.name.trim();
+            if name.is_empty() {
+                continue;
+            }
+            let color = colors.entry(name.to_string()).or_default();
+            if color.is_none() {
+                color.clone_from(&tag.color);
+            }
+        }
+        if colors.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut ids: HashMap<String, i32> = entity::tags::Entity::find()
+            .filter(entity::tags::Column::UserId.eq(user_id))
+            .filter(entity::tags::Column::Name.is_in(colors.keys().cloned()))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|tag| (tag.name, tag.id))
+            .collect();
+
+        let now = Utc::now().fixed_offset();
+        for (name, color) in colors {
+            if ids.contains_key(&name) {
+                continue;
+            }
+
+            let tag = entity::tags::ActiveModel {
+                id: NotSet,
+                user_id: Set(user_id),
+                name: Set(name.clone()),
+                color: Set(color),
+                created_at: Set(now),
+            }
+            .insert(db)
+            .await?;
+            ids.insert(name, tag.id);
+        }
+        Ok(ids)
+    }
+
+    pub async fn attach<'a, C: ConnectionTrait>(
+        db: &C,
+        words: impl IntoIterator<Item = (i32, &'a [ImportTag])>,
+        tag_ids: &HashMap<String, i32>,
+    ) -> Result<(), DbErr> {
+        let models: Vec<entity::word_tags::ActiveModel> = words
+            .into_iter()
+            .flat_map(|(word_id, tags)| {
+                tags
+                    .iter()
+                    .filter_map(|tag| tag_ids.get(tag.name.trim()).copied())
+                    .collect::<BTreeSet<i32>>()
+                    .into_iter()
+                    .map(move |tag_id| entity::word_tags::ActiveModel {
+                        word_id: Set(word_id),
+                        tag_id: Set(tag_id),
+                    })
+            })
+            .collect();
+
+        for chunk in models.chunks(INSERT_BATCH_SIZE) {
+            entity::word_tags::Entity::insert_many(chunk.to_vec())
+                .on_conflict(
+                    OnConflict::columns([
+                        entity::word_tags::Column::WordId,
+                        entity::word_tags::Column::TagId,
+                    ])
+                    .do_nothing()
+                    .to_owned(),
+                )
+                .do_nothing()
+                .exec_without_returning(db)
+                .await?;
+        }
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,17 @@
+use chrono::FixedOffset;
+
+use crate::error::AppError;
+
+pub struct ClientTime;
+
+impl ClientTime {
+    // Client time zones are sent as minutes east of UTC, like JavaScript's negated
+    // getTimezoneOffset(). Anything beyond a day either way is rejected.
+    pub fn offset(tz_offset_minutes: Option<i32>) -> Result<FixedOffset, AppError> {
+        tz_offset_minutes
+            .unwrap_or(0)
+            .checked_mul(60)
+            .and_then(FixedOffset::east_opt)
+            .ok_or_else(|| AppError::Validation("Invalid tz_offset".to_string()))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,4 @@
+pub mod error;
+pub mod fonts;
+pub mod layout;
+pub mod pdf;
\ No newline at end of file
@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum WorksheetError {
+    #[error("No font available for PDF export: set PDF_FONT_PATH to a TrueType or OpenType font")]
+    NoFont,
+
+    #[error("The PDF fonts have no glyphs for {0}: set PDF_FONT_PATH to a font that covers them, such as Noto Sans CJK")]
+    MissingGlyphs(String),
+
+    #[error("Font error: {0}")]
+    FontError(String),
+}
\ No newline at end of file
@@ -0,0 +1,105 @@
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use ttf_parser::Face;
+use ttf_parser::Tag;
+
+use crate::worksheet::error::WorksheetError;
+
+// Used when PDF_FONT_PATH is not set. Every font that exists is loaded and characters
+// are drawn with the first one that has a glyph, so a Latin font can come first.
+const DEFAULT_FONT_PATHS: [&str; 14] = [
+    "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf",
+    "/usr/share/fonts/dejavu/DejaVuSans.ttf",
+    "/usr/share/fonts/TTF/DejaVuSans.ttf",
+    "/usr/share/fonts/dejavu-sans-fonts/DejaVuSans.ttf",
+    "/usr/share/fonts/opentype/noto/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/noto/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/noto-cjk/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/google-noto-cjk/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/truetype/wqy/wqy-microhei.ttc",
+    "/usr/share/fonts/truetype/wqy/wqy-zenhei.ttc",
+    "/usr/share/fonts/wenquanyi/wqy-microhei/wqy-microhei.ttc",
+    "/usr/share/fonts/truetype/droid/DroidSansFallbackFull.ttf",
+    "/usr/share/fonts/truetype/arphic/uming.ttc",
+    "/Library/Fonts/Arial Unicode.ttf",
+];
+const CFF2: Tag = Tag::from_bytes(b"CFF2");
+
+pub struct WorksheetFont {
+    pub path: PathBuf,
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, Default)]
+pub struct WorksheetFonts {
+    fonts: Arc<Vec<WorksheetFont>>,
+}
+
+impl WorksheetFonts {
+    pub fn load(paths: &[PathBuf]) -> Self {
+        let mut fonts = Vec::new();
+        for path in paths {
+            match WorksheetFont::read(path) {
+                Ok(font) => fonts.push(font),
+                Err(e) => tracing::error!("Failed to load PDF font {}: {}", path.display(), e),
+            }
+        }
+        if paths.is_empty() {
+            fonts.extend(
+                DEFAULT_FONT_PATHS
+                    .iter()
+                    .map(Path::new)
+                    .filter(|path| path.exists())
+                    .filter_map(|path| WorksheetFont::read(path).ok()),
+            );
+        }
+
+        match fonts.is_empty() {
+            true => tracing::warn!("No PDF fonts found; set PDF_FONT_PATH to enable PDF export"),
+            false => tracing::info!(
+                "PDF fonts: {}",
+                fonts
+                    .iter()
+                    .map(|font| font.path.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+
+        Self {
+            fonts: Arc::new(fonts),
+        }
+    }
+
+    pub fn fonts(&self) -> Result<&[WorksheetFont], WorksheetError> {
+        match self.fonts.is_empty() {
+            true => Err(WorksheetError::NoFont),
+            false => Ok(&self.fonts),
+        }
+    }
+}
+
+impl WorksheetFont {
+    fn read(path: &Path) -> Result<Self, WorksheetError> {
+        let data = std::fs::read(path).map_err(|e| WorksheetError::FontError(e.to_string()))?;
+        let font = Self {
+            path: path.to_path_buf(),
+            data,
+        };
+
+        // CFF2 outlines need PDF 2.0, which most viewers cannot display yet.
+        if font.face()?.raw_face().table(CFF2).is_some() {
+            return Err(WorksheetError::FontError(
+                "CFF2 (variable OpenType) fonts are not supported".to_string(),
+            ));
+        }
+        Ok(font)
+    }
+
+    // Collections (.ttc) use their first face.
+    pub fn face(&self) -> Result<Face<'_>, WorksheetError> {
+        Face::parse(&self.data, 0).map_err(|e| WorksheetError::FontError(e.to_string()))
+    }
+}
\ No newline at end of file
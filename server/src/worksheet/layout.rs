@@ -0,0 +1,369 @@
+use serde::Deserialize;
+
+use crate::import::data::ImportChapter;
+use crate::import::data::ImportWord;
+use crate::import::data::ImportWordbook;
+use crate::shuffle::Shuffler;
+use crate::worksheet::error::WorksheetError;
+use crate::worksheet::fonts::WorksheetFonts;
+use crate::worksheet::pdf::PdfDocument;
+use crate::worksheet::pdf::PAGE_HEIGHT;
+use crate::worksheet::pdf::PAGE_WIDTH;
+
+const MARGIN: f32 = 48.0;
+const FOOTER_Y: f32 = 28.0;
+const TITLE_SIZE: f32 = 18.0;
+const HEADING_SIZE: f32 = 13.0;
+const BODY_SIZE: f32 = 11.0;
+const SMALL_SIZE: f32 = 9.0;
+const LINE_SPACING: f32 = 1.35;
+const CELL_PADDING: f32 = 6.0;
+const BLANK_ROW_HEIGHT: f32 = 28.0;
+const NUMBER_COLUMN_WIDTH: f32 = 28.0;
+const COLUMN_GAP: f32 = 12.0;
+const SOURCE_COLUMN_SHARE: f32 = 0.42;
+const TEXT_GRAY: f32 = 0.0;
+const MUTED_GRAY: f32 = 0.4;
+const RULE_GRAY: f32 = 0.75;
+
+// Mirrors the web app's display modes, plus a shuffled dictation sheet with an answer key.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorksheetLayout {
+    #[default]
+    Bilingual,
+    Original,
+    Translation,
+    Dictation,
+}
+
+#[derive(Clone, Copy)]
+struct Columns {
+    source: bool,
+    translation: bool,
+}
+
+struct Section<'a> {
+    heading: Option<&'a str>,
+    words: Vec<&'a ImportWord>,
+}
+
+struct Cell {
+    lines: Vec<(String, f32, f32)>,
+    blank: bool,
+}
+
+pub struct WorksheetPdf<'a> {
+    doc: PdfDocument<'a>,
+    layout: WorksheetLayout,
+    y: f32,
+}
+
+impl WorksheetLayout {
+    fn subtitle(self) -> &'static str {
+        match self {
+            Self::Bilingual => "Vocabulary list",
+            Self::Original => "Write the translation of each word",
+            Self::Translation => "Write the word for each translation",
+            Self::Dictation => "Dictation: write down each word you hear and its translation",
+        }
+    }
+
+    fn columns(self) -> Columns {
+        Columns {
+            source: matches!(self, Self::Bilingual | Self::Original),
+            translation: matches!(self, Self::Bilingual | Self::Translation),
+        }
+    }
+}
+
+impl<'a> WorksheetPdf<'a> {
+    pub fn wordbook(
+        fonts: &'a WorksheetFonts,
+        data: &'a ImportWordbook,
+        layout: WorksheetLayout,
+    ) -> Result<Vec<u8>, WorksheetError> {
+        let sections = data
+            .chapters
+            .iter()
+            .filter(|chapter| !chapter.words.is_empty())
+            .map(|chapter| Section {
+                heading: Some(chapter.name.as_str()),
+                words: chapter.words.iter().collect(),
+            })
+            .collect();
+        Self::new(fonts, layout)?.write(&data.name, sections)
+    }
+
+    pub fn chapter(
+        fonts: &'a WorksheetFonts,
+        data: &'a ImportChapter,
+        layout: WorksheetLayout,
+    ) -> Result<Vec<u8>, WorksheetError> {
+        let sections = vec![Section {
+            heading: None,
+            words: data.words.iter().collect(),
+        }];
+        Self::new(fonts, layout)?.write(&data.name, sections)
+    }
+
+    fn new(fonts: &'a WorksheetFonts, layout: WorksheetLayout) -> Result<Self, WorksheetError> {
+        Ok(Self {
+            doc: PdfDocument::new(fonts.fonts()?)?,
+            layout,
+            y: 0.0,
+        })
+    }
+
+    fn write(mut self, title: &str, mut sections: Vec<Section>) -> Result<Vec<u8>, WorksheetError> {
+        if self.layout == WorksheetLayout::Dictation {
+            let shuffler = Shuffler::new();
+            for section in &mut sections {
+                shuffler.shuffle_by(&mut section.words, |word| {
+                    (word.source.clone(), word.translation.clone())
+                });
+            }
+        }
+
+        let word_count = sections.iter().map(|section| section.words.len()).sum();
+        self.title_page(title, self.layout.subtitle(), word_count);
+        if self.layout != WorksheetLayout::Bilingual {
+            self.name_line();
+        }
+        self.sections(&sections, self.layout.columns());
+
+        if self.layout == WorksheetLayout::Dictation {
+            self.title_page(title, "Answer key", word_count);
+            self.sections(&sections, WorksheetLayout::Bilingual.columns());
+        }
+
+        self.page_numbers();
+        self.doc.finish(title)
+    }
+
+    fn title_page(&mut self, title: &str, subtitle: &str, word_count: usize) {
+        self.doc.add_page();
+        self.y = PAGE_HEIGHT - MARGIN;
+        for line in self.wrap(title, TITLE_SIZE, PAGE_WIDTH - 2.0 * MARGIN) {
+            self.y -= TITLE_SIZE * LINE_SPACING;
+            self.doc.text(MARGIN, self.y, &line, TITLE_SIZE, TEXT_GRAY);
+        }
+        self.y -= BODY_SIZE * LINE_SPACING;
+        let subtitle = format!("{} · {} words", subtitle, word_count);
+        self.doc.text(MARGIN, self.y, &subtitle, BODY_SIZE, MUTED_GRAY);
+        self.y -= BODY_SIZE;
+    }
+
+    fn name_line(&mut self) {
+        self.y -= BODY_SIZE * 2.0;
+        let mut x = MARGIN;
+        for (label, width) in [("Name", 180.0), ("Date", 110.0), ("Score", 70.0)] {
+            self.doc.text(x, self.y, label, BODY_SIZE, TEXT_GRAY);
+            x += self.doc.text_width(label, BODY_SIZE) + 6.0;
+            self.doc.line((x, self.y - 2.0), (x + width, self.y - 2.0), 0.5, TEXT_GRAY);
+            x += width + 18.0;
+        }
+        self.y -= BODY_SIZE;
+    }
+
+    fn sections(&mut self, sections: &[Section], columns: Columns) {
+        for (idx, section) in sections.iter().enumerate() {
+            // Each chapter starts on its own page so it can be handed out separately.
+            if idx > 0 {
+                self.doc.add_page();
+                self.y = PAGE_HEIGHT - MARGIN;
+            }
+            if let Some(heading) = section.heading {
+                self.y -= HEADING_SIZE;
+                for line in self.wrap(heading, HEADING_SIZE, PAGE_WIDTH - 2.0 * MARGIN) {
+                    self.y -= HEADING_SIZE * LINE_SPACING;
+                    self.doc.text(MARGIN, self.y, &line, HEADING_SIZE, TEXT_GRAY);
+                }
+            }
+            self.y -= BODY_SIZE;
+            self.table_header();
+            for (number, word) in section.words.iter().enumerate() {
+                self.row(number + 1, word, columns);
+            }
+        }
+    }
+
+    fn table_header(&mut self) {
+        let (source_x, translation_x, _) = Self::column_positions();
+        self.y -= SMALL_SIZE * LINE_SPACING;
+        self.doc.text(MARGIN, self.y, "#", SMALL_SIZE, MUTED_GRAY);
+        self.doc.text(source_x, self.y, "Word", SMALL_SIZE, MUTED_GRAY);
+        self.doc.text(translation_x, self.y, "Translation", SMALL_SIZE, MUTED_GRAY);
+        self.y -= CELL_PADDING;
+        self.rule(0.75);
+    }
+
+    fn row(&mut self, number: usize, word: &ImportWord, columns: Columns) {
+        let (source_x, translation_x, translation_width) = Self::column_positions();
+        let source_width = translation_x - source_x - COLUMN_GAP;
+
+        let source = match columns.source {
+            true => self.source_cell(word, source_width),
+            false => Cell::blank(),
+        };
+        let translation = match columns.translation {
+            true => self.translation_cell(word, translation_width),
+            false => Cell::blank(),
+        };
+        let height = source.height().max(translation.height()) + 2.0 * CELL_PADDING;
+
+        if self.y - height < MARGIN {
+            self.doc.add_page();
+            self.y = PAGE_HEIGHT - MARGIN;
+            self.table_header();
+        }
+
+        let top = self.y - CELL_PADDING;
+        self.doc.text(MARGIN, top - BODY_SIZE, &number.to_string(), BODY_SIZE, MUTED_GRAY);
+        self.cell(&source, source_x, source_width, top, height);
+        self.cell(&translation, translation_x, translation_width, top, height);
+
+        self.y -= height;
+        self.rule(0.25);
+    }
+
+    fn source_cell(&self, word: &ImportWord, width: f32) -> Cell {
+        let mut lines = self.wrap_cell(&word.source, BODY_SIZE, TEXT_GRAY, width);
+        if let Some(phonetic) = word.phonetic.as_deref().filter(|p| !p.trim().is_empty()) {
+            lines.extend(self.wrap_cell(phonetic, SMALL_SIZE, MUTED_GRAY, width));
+        }
+        Cell { lines, blank: false }
+    }
+
+    fn translation_cell(&self, word: &ImportWord, width: f32) -> Cell {
+        let text = match word.part_of_speech.as_deref().filter(|p| !p.trim().is_empty()) {
+            Some(pos) => format!("{} {}", pos.trim(), word.translation),
+            None => word.translation.clone(),
+        };
+        Cell {
+            lines: self.wrap_cell(&text, BODY_SIZE, TEXT_GRAY, width),
+            blank: false,
+        }
+    }
+
+    fn cell(&mut self, cell: &Cell, x: f32, width: f32, top: f32, height: f32) {
+        if cell.blank {
+            let y = self.y - height + CELL_PADDING;
+            self.doc.line((x, y), (x + width, y), 0.5, MUTED_GRAY);
+            return;
+        }
+        let mut y = top;
+        for (line, size, gray) in &cell.lines {
+            self.doc.text(x, y - size, line, *size, *gray);
+            y -= size * LINE_SPACING;
+        }
+    }
+
+    fn rule(&mut self, width: f32) {
+        self.doc.line(
+            (MARGIN, self.y),
+            (PAGE_WIDTH - MARGIN, self.y),
+            width,
+            RULE_GRAY,
+        );
+    }
+
+    fn page_numbers(&mut self) {
+        let count = self.doc.page_count();
+        for page in 0..count {
+            let label = format!("{} / {}", page + 1, count);
+            let x = (PAGE_WIDTH - self.doc.text_width(&label, SMALL_SIZE)) / 2.0;
+            self.doc.select_page(page);
+            self.doc.text(x, FOOTER_Y, &label, SMALL_SIZE, MUTED_GRAY);
+        }
+    }
+
+    fn column_positions() -> (f32, f32, f32) {
+        let source_x = MARGIN + NUMBER_COLUMN_WIDTH;
+        let width = PAGE_WIDTH - MARGIN - source_x;
+        let translation_x = source_x + width * SOURCE_COLUMN_SHARE;
+        (source_x, translation_x, PAGE_WIDTH - MARGIN - translation_x)
+    }
+
+    fn wrap_cell(&self, text: &str, size: f32, gray: f32, width: f32) -> Vec<(String, f32, f32)> {
+        self.wrap(text, size, width)
+            .into_iter()
+            .map(|line| (line, size, gray))
+            .collect()
+    }
+
+    // Breaks at spaces, and between any two CJK characters since those scripts have none.
+    fn wrap(&self, text: &str, size: f32, width: f32) -> Vec<String> {
+        let mut lines = Vec::new();
+        for paragraph in text.lines() {
+            let mut line = String::new();
+            for token in Self::tokens(paragraph) {
+                let candidate = format!("{}{}", line, token);
+                if self.doc.text_width(candidate.trim_end(), size) <= width {
+                    line = candidate;
+                    continue;
+                }
+                if !line.trim().is_empty() {
+                    lines.push(line.trim_end().to_string());
+                }
+                line = String::new();
+                for c in token.trim_start().chars() {
+                    if !line.is_empty() && self.doc.text_width(&format!("{}{}", line, c), size) > width {
+                        lines.push(std::mem::take(&mut line));
+                    }
+                    line.push(c);
+                }
+            }
+            if !line.trim().is_empty() {
+                lines.push(line.trim_end().to_string());
+            }
+        }
+        lines
+    }
+
+    fn tokens(text: &str) -> Vec<String> {
+        let mut tokens: Vec<String> = Vec::new();
+        for c in text.chars() {
+            let joins = match tokens.last() {
+                Some(last) => {
+                    let prev = last.chars().last().unwrap_or(' ');
+                    !Self::is_cjk(c) && !Self::is_cjk(prev) && !prev.is_whitespace()
+                        || c.is_whitespace() && prev.is_whitespace()
+                }
+                None => false,
+            };
+            match (joins, tokens.last_mut()) {
+                (true, Some(last)) => last.push(c),
+                _ => tokens.push(c.to_string()),
+            }
+        }
+        tokens
+    }
+
+    fn is_cjk(c: char) -> bool {
+        matches!(
+            c as u32,
+            0x2E80..=0x9FFF | 0xAC00..=0xD7AF | 0xF900..=0xFAFF | 0xFF00..=0xFFEF | 0x20000..=0x2FFFF
+        )
+    }
+}
+
+impl Cell {
+    fn blank() -> Self {
+        Self {
+            lines: Vec::new(),
+            blank: true,
+        }
+    }
+
+    fn height(&self) -> f32 {
+        match self.blank {
+            true => BLANK_ROW_HEIGHT,
+            false => self
+                .lines
+                .iter()
+                .map(|(_, size, _)| size * LINE_SPACING)
+                .sum(),
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,358 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::io::Write;
+
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use pdf_writer::types::CidFontType;
+use pdf_writer::types::FontFlags;
+use pdf_writer::types::SystemInfo;
+use pdf_writer::types::UnicodeCmap;
+use pdf_writer::Content;
+use pdf_writer::Filter;
+use pdf_writer::Finish;
+use pdf_writer::Name;
+use pdf_writer::Pdf;
+use pdf_writer::Rect;
+use pdf_writer::Ref;
+use pdf_writer::Str;
+use pdf_writer::TextStr;
+use ttf_parser::name_id;
+use ttf_parser::Face;
+use ttf_parser::GlyphId;
+use ttf_parser::Tag;
+
+use crate::worksheet::error::WorksheetError;
+use crate::worksheet::fonts::WorksheetFont;
+
+pub const PAGE_WIDTH: f32 = 595.28;
+pub const PAGE_HEIGHT: f32 = 841.89;
+
+const CFF: Tag = Tag::from_bytes(b"CFF ");
+const MAX_MISSING_GLYPHS: usize = 10;
+const SYSTEM_INFO: SystemInfo = SystemInfo {
+    registry: Str(b"Adobe"),
+    ordering: Str(b"Identity"),
+    supplement: 0,
+};
+
+struct PdfFont<'a> {
+    source: &'a WorksheetFont,
+    face: Face<'a>,
+    // Glyph id to the character it was drawn for, used for subsetting and copy-paste.
+    used: BTreeMap<u16, char>,
+}
+
+// A minimal PDF writer for text and rules. Text is drawn glyph by glyph without shaping,
+// which is enough for word lists in Latin and CJK scripts.
+pub struct PdfDocument<'a> {
+    fonts: Vec<PdfFont<'a>>,
+    pages: Vec<Content>,
+    current: usize,
+    missing: BTreeSet<char>,
+}
+
+impl<'a> PdfDocument<'a> {
+    pub fn new(fonts: &'a [WorksheetFont]) -> Result<Self, WorksheetError> {
+        let fonts = fonts
+            .iter()
+            .map(|source| {
+                Ok(PdfFont {
+                    source,
+                    face: source.face()?,
+                    used: BTreeMap::new(),
+                })
+            })
+            .collect::<Result<Vec<_>, WorksheetError>>()?;
+
+        Ok(Self {
+            fonts,
+            pages: Vec::new(),
+            current: 0,
+            missing: BTreeSet::new(),
+        })
+    }
+
+    pub fn add_page(&mut self) {
+        self.pages.push(Content::new());
+        self.current = self.pages.len() - 1;
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    pub fn select_page(&mut self, page: usize) {
+        self.current = page;
+    }
+
+    pub fn text_width(&self, text: &str, size: f32) -> f32 {
+        text.chars()
+            .filter_map(|c| self.glyph(c))
+            .map(|(font, glyph)| self.advance(font, glyph, size))
+            .sum()
+    }
+
+    pub fn text(&mut self, x: f32, y: f32, text: &str, size: f32, gray: f32) {
+        let mut runs: Vec<(usize, Vec<u8>)> = Vec::new();
+        for c in text.chars().filter(|c| !c.is_control()) {
+            let Some((font, glyph)) = self.glyph(c) else {
+                self.missing.insert(c);
+                continue;
+            };
+            self.fonts[font].used.entry(glyph.0).or_insert(c);
+            let cid = self.fonts[font].cid(glyph);
+            match runs.last_mut() {
+                Some((run_font, bytes)) if *run_font == font => bytes.extend(cid.to_be_bytes()),
+                _ => runs.push((font, cid.to_be_bytes().to_vec())),
+            }
+        }
+        if runs.is_empty() {
+            return;
+        }
+
+        let content = &mut self.pages[self.current];
+        content.set_fill_gray(gray).begin_text().next_line(x, y);
+        for (font, bytes) in &runs {
+            content
+                .set_font(Name(Self::font_name(*font).as_bytes()), size)
+                .show(Str(bytes));
+        }
+        content.end_text();
+    }
+
+    pub fn line(&mut self, from: (f32, f32), to: (f32, f32), width: f32, gray: f32) {
+        self.pages[self.current]
+            .set_stroke_gray(gray)
+            .set_line_width(width)
+            .move_to(from.0, from.1)
+            .line_to(to.0, to.1)
+            .stroke();
+    }
+
+    pub fn finish(self, title: &str) -> Result<Vec<u8>, WorksheetError> {
+        if !self.missing.is_empty() {
+            let mut chars: Vec<String> = self
+                .missing
+                .iter()
+                .take(MAX_MISSING_GLYPHS)
+                .map(|c| format!("'{}'", c))
+                .collect();
+            if self.missing.len() > MAX_MISSING_GLYPHS {
+                chars.push("…".to_string());
+            }
+            return Err(WorksheetError::MissingGlyphs(chars.join(", ")));
+        }
+
+        let mut pdf = Pdf::new();
+        let mut next_ref = Ref::new(1);
+        let catalog_id = next_ref.bump();
+        let page_tree_id = next_ref.bump();
+        let info_id = next_ref.bump();
+
+        let font_ids: Vec<Option<Ref>> = self
+            .fonts
+            .iter()
+            .map(|font| (!font.used.is_empty()).then(|| next_ref.bump()))
+            .collect();
+        for (font, id) in self.fonts.iter().zip(&font_ids) {
+            if let Some(id) = id {
+                font.write(&mut pdf, *id, &mut next_ref)?;
+            }
+        }
+
+        let page_ids: Vec<Ref> = self.pages.iter().map(|_| next_ref.bump()).collect();
+        pdf.catalog(catalog_id).pages(page_tree_id);
+        pdf.pages(page_tree_id)
+            .kids(page_ids.iter().copied())
+            .count(page_ids.len() as i32);
+        pdf.document_info(info_id).title(TextStr(title));
+
+        for (content, page_id) in self.pages.into_iter().zip(page_ids) {
+            let content_id = next_ref.bump();
+            let mut page = pdf.page(page_id);
+            page.media_box(Rect::new(0.0, 0.0, PAGE_WIDTH, PAGE_HEIGHT))
+                .parent(page_tree_id)
+                .contents(content_id);
+            let mut resources = page.resources();
+            let mut fonts = resources.fonts();
+            for (idx, id) in font_ids.iter().enumerate() {
+                if let Some(id) = id {
+                    fonts.pair(Name(Self::font_name(idx).as_bytes()), *id);
+                }
+            }
+            fonts.finish();
+            resources.finish();
+            page.finish();
+
+            pdf.stream(content_id, &Self::deflate(&content.finish())?)
+                .filter(Filter::FlateDecode);
+        }
+
+        Ok(pdf.finish())
+    }
+
+    fn glyph(&self, c: char) -> Option<(usize, GlyphId)> {
+        let c = if c.is_whitespace() { ' ' } else { c };
+        self.fonts
+            .iter()
+            .enumerate()
+            .find_map(|(idx, font)| font.face.glyph_index(c).map(|glyph| (idx, glyph)))
+    }
+
+    fn advance(&self, font: usize, glyph: GlyphId, size: f32) -> f32 {
+        let face = &self.fonts[font].face;
+        f32::from(face.glyph_hor_advance(glyph).unwrap_or(0)) * size
+            / f32::from(face.units_per_em())
+    }
+
+    fn font_name(font: usize) -> String {
+        format!("F{}", font)
+    }
+
+    fn deflate(data: &[u8]) -> Result<Vec<u8>, WorksheetError> {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(data)
+            .and_then(|_| encoder.finish())
+            .map_err(|e| WorksheetError::FontError(e.to_string()))
+    }
+}
+
+impl PdfFont<'_> {
+    fn is_cff(&self) -> bool {
+        self.face.raw_face().table(CFF).is_some()
+    }
+
+    // CID-keyed CFF fonts address glyphs by CID; for everything else CID and glyph id match.
+    fn cid(&self, glyph: GlyphId) -> u16 {
+        self.face
+            .tables()
+            .cff
+            .and_then(|cff| cff.glyph_cid(glyph))
+            .unwrap_or(glyph.0)
+    }
+
+    // Embeds the font as a Type0 font with Identity-H encoding, keeping only the used glyphs.
+    fn write(&self, pdf: &mut Pdf, type0_id: Ref, next_ref: &mut Ref) -> Result<(), WorksheetError> {
+        let cid_id = next_ref.bump();
+        let descriptor_id = next_ref.bump();
+        let cmap_id = next_ref.bump();
+        let data_id = next_ref.bump();
+        let is_cff = self.is_cff();
+
+        let base_font = format!("{}+{}", self.subset_tag(), self.postscript_name());
+        let type0_name = match is_cff {
+            true => format!("{}-Identity-H", base_font),
+            false => base_font.clone(),
+        };
+        pdf.type0_font(type0_id)
+            .base_font(Name(type0_name.as_bytes()))
+            .encoding_predefined(Name(b"Identity-H"))
+            .descendant_font(cid_id)
+            .to_unicode(cmap_id);
+
+        let mut cid = pdf.cid_font(cid_id);
+        cid.subtype(if is_cff { CidFontType::Type0 } else { CidFontType::Type2 })
+            .base_font(Name(base_font.as_bytes()))
+            .system_info(SYSTEM_INFO)
+            .font_descriptor(descriptor_id)
+            .default_width(0.0);
+        if !is_cff {
+            cid.cid_to_gid_map_predefined(Name(b"Identity"));
+        }
+        let mut widths = cid.widths();
+        for glyph in self.used.keys() {
+            let advance = self.face.glyph_hor_advance(GlyphId(*glyph)).unwrap_or(0);
+            widths.consecutive(self.cid(GlyphId(*glyph)), [self.to_font_units(advance)]);
+        }
+        widths.finish();
+        cid.finish();
+
+        let bbox = self.face.global_bounding_box();
+        let mut descriptor = pdf.font_descriptor(descriptor_id);
+        descriptor
+            .name(Name(base_font.as_bytes()))
+            .flags(FontFlags::SYMBOLIC)
+            .bbox(Rect::new(
+                self.to_font_units(bbox.x_min),
+                self.to_font_units(bbox.y_min),
+                self.to_font_units(bbox.x_max),
+                self.to_font_units(bbox.y_max),
+            ))
+            .italic_angle(self.face.italic_angle())
+            .ascent(self.to_font_units(self.face.ascender()))
+            .descent(self.to_font_units(self.face.descender()))
+            .cap_height(self.to_font_units(
+                self.face.capital_height().unwrap_or(self.face.ascender()),
+            ))
+            .stem_v(80.0);
+        match is_cff {
+            true => descriptor.font_file3(data_id),
+            false => descriptor.font_file2(data_id),
+        };
+        descriptor.finish();
+
+        let mut cmap = UnicodeCmap::new(Name(b"Custom"), SYSTEM_INFO);
+        for (glyph, c) in &self.used {
+            cmap.pair(self.cid(GlyphId(*glyph)), *c);
+        }
+        pdf.cmap(cmap_id, &cmap.finish());
+
+        let data = PdfDocument::deflate(&self.subset()?)?;
+        let mut stream = pdf.stream(data_id, &data);
+        stream.filter(Filter::FlateDecode);
+        if is_cff {
+            stream.pair(Name(b"Subtype"), Name(b"CIDFontType0C"));
+        }
+        stream.finish();
+        Ok(())
+    }
+
+    // TrueType fonts are embedded as a whole (subset) font file, CFF fonts as the bare CFF table.
+    fn subset(&self) -> Result<Vec<u8>, WorksheetError> {
+        let glyphs: Vec<u16> = std::iter::once(0).chain(self.used.keys().copied()).collect();
+        let subset = subsetter::subset(&self.source.data, 0, subsetter::Profile::pdf(&glyphs))
+            .map_err(|e| WorksheetError::FontError(e.to_string()))?;
+        if !self.is_cff() {
+            return Ok(subset);
+        }
+        ttf_parser::RawFace::parse(&subset, 0)
+            .ok()
+            .and_then(|face| face.table(CFF))
+            .map(<[u8]>::to_vec)
+            .ok_or_else(|| WorksheetError::FontError("Missing CFF table".to_string()))
+    }
+
+    fn postscript_name(&self) -> String {
+        let name = self
+            .face
+            .names()
+            .into_iter()
+            .filter(|name| name.name_id == name_id::POST_SCRIPT_NAME)
+            .find_map(|name| name.to_string())
+            .unwrap_or_else(|| "Font".to_string());
+        name.chars().filter(|c| c.is_ascii_alphanumeric() || *c == '-').collect()
+    }
+
+    // Subset fonts are named with six uppercase letters derived from their glyphs.
+    fn subset_tag(&self) -> String {
+        let mut hash = self
+            .used
+            .keys()
+            .fold(0xcbf2_9ce4_8422_2325_u64, |hash, glyph| {
+                (hash ^ u64::from(*glyph)).wrapping_mul(0x0100_0000_01b3)
+            });
+        (0..6)
+            .map(|_| {
+                let letter = b'A' + (hash % 26) as u8;
+                hash /= 26;
+                letter as char
+            })
+            .collect()
+    }
+
+    fn to_font_units(&self, value: impl Into<f32>) -> f32 {
+        value.into() * 1000.0 / f32::from(self.face.units_per_em())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,26 @@
+import type { ImportJob, ImportResult } from '@/types'
+
+// Imports run as background jobs; follow the job's progress events until it finishes.
+export function waitForImportJob(
+  job: ImportJob,
+  onProgress?: (job: ImportJob) => void
+): Promise<ImportResult> {
+  return new Promise((resolve, reject) => {
+    const events = new EventSource(`/api/import/jobs/${job.id}/events`, { withCredentials: true })
+    events.addEventListener('progress', (event) => {
+      const current = JSON.parse((event as MessageEvent).data) as ImportJob
+      onProgress?.(current)
+      if (current.status === 'succeeded' && current.result) {
+        events.close()
+        resolve(current.result)
+      } else if (current.status === 'failed') {
+        events.close()
+        reject(new Error(current.errors.join('\n') || 'Import failed'))
+      }
+    })
+    events.onerror = () => {
+      events.close()
+      reject(new Error('Lost connection to import job'))
+    }
+  })
+}
\ No newline at end of file
@@ -211,7 +211,18 @@ export default {
       json: 'JSON',
       xml: 'XML',
       xlsx: 'Excel',
-      csv: 'CSV'
+      ods: 'OpenDocument',
+      csv: 'CSV',
+      md: 'Markdown',
+      txt: 'Plain text',
+      flashcards: 'Quizlet',
+      apkg: 'Anki'
+    },
+    pdfLayouts: {
+      bilingual: 'PDF list',
+      original: 'PDF words only',
+      translation: 'PDF meanings only',
+      dictation: 'PDF dictation'
     },
     name: 'Name',
     namePlaceholder: 'Enter name...',
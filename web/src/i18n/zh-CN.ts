@@ -211,7 +211,18 @@ export default {
       json: 'JSON',
       xml: 'XML',
       xlsx: 'Excel',
-      csv: 'CSV'
+      ods: 'OpenDocument',
+      csv: 'CSV',
+      md: 'Markdown',
+      txt: '纯文本',
+      flashcards: 'Quizlet',
+      apkg: 'Anki'
+    },
+    pdfLayouts: {
+      bilingual: 'PDF 对照表',
+      original: 'PDF 写释义',
+      translation: 'PDF 写单词',
+      dictation: 'PDF 听写'
     },
     name: '名称',
     namePlaceholder: '输入名称...',
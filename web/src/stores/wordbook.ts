@@ -1,6 +1,6 @@
 import { defineStore } from 'pinia'
 import { ref } from 'vue'
-import type { Wordbook, Chapter, Word, Tag, DisplayMode } from '@/types'
+import type { Wordbook, Chapter, Word, WordExample, Tag, DisplayMode } from '@/types'
 import { apiClient } from '@/api/client'
 
 const LINE_HEIGHT_KEY = 'plain-word-line-height'
@@ -75,13 +75,13 @@ export const useWordbookStore = defineStore('wordbook', () => {
     words.value = await apiClient.get<Word[]>(`/chapters/${chapterId}/words`)
   }
 
-  async function createWord(chapterId: number | string, data: { source: string; translation: string; note?: string }) {
+  async function createWord(chapterId: number | string, data: { source: string; translation: string; note?: string; phonetic?: string; part_of_speech?: string; examples?: WordExample[] }) {
     const newWord = await apiClient.post<Word>(`/chapters/${chapterId}/words`, data)
     words.value.push(newWord)
     return newWord
   }
 
-  async function updateWord(chapterId: number | string, wordId: number | string, data: { source?: string; translation?: string; note?: string }) {
+  async function updateWord(chapterId: number | string, wordId: number | string, data: { source?: string; translation?: string; note?: string; phonetic?: string; part_of_speech?: string; examples?: WordExample[] }) {
     const updated = await apiClient.put<Word>(`/chapters/${chapterId}/words/${wordId}`, data)
     const idx = words.value.findIndex(w => w.id === Number(wordId))
     if (idx !== -1) {
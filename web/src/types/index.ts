@@ -34,14 +34,44 @@ export interface Word {
   source: string
   translation: string
   note: string | null
+  phonetic: string | null
+  part_of_speech: string | null
+  examples: WordExample[]
   sort_order: number
   created_at: string
   tags?: Tag[]
 }
 
+export interface WordExample {
+  id?: number
+  sentence: string
+  translation: string | null
+}
+
 export type DisplayMode = 'original' | 'translation' | 'bilingual'
 
 export interface ApiResponse<T> {
   data?: T
   error?: string
+}
+export interface ImportResult {
+  chapters_created: number
+  words_created: number
+  words_updated: number
+  words_skipped: number
+}
+
+export interface ImportJob {
+  id: number
+  kind: string
+  file_name: string
+  wordbook_id: number | null
+  status: 'queued' | 'running' | 'succeeded' | 'failed'
+  total_rows: number
+  processed_rows: number
+  result?: ImportResult
+  errors: string[]
+  created_at: string
+  started_at: string | null
+  finished_at: string | null
 }
\ No newline at end of file